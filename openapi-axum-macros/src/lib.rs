@@ -0,0 +1,100 @@
+//! `openapi_axum!("api.yaml")`: the generated server, expanded in place
+//!
+//! An alternative to writing files with the CLI or a build script, sharing
+//! the same generator. The spec path is relative to the crate's `Cargo.toml`.
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::quote;
+use std::path::{Path, PathBuf};
+use syn::{parse_macro_input, LitStr};
+
+use openapi_axum_generator::build::{single_module, tracked_files};
+use openapi_axum_generator::file_utils::SpecError;
+use openapi_axum_generator::{Generator, GeneratorError};
+
+/// Expands to the modules generated for a spec, with `app()` routing every
+/// operation to an implementation of each module's `Handlers` trait. Invoke
+/// it at the crate root, as generated code uses `crate::` paths:
+///
+/// ```ignore
+/// openapi_axum_macros::openapi_axum!("api.yaml");
+///
+/// #[derive(Clone)]
+/// struct Store;
+///
+/// #[axum::async_trait]
+/// impl pets::handlers::Handlers for Store {
+///     async fn list_pets(
+///         &self,
+///         query: pets::handlers::ListPetsQuery,
+///     ) -> Result<axum::response::Response, errors::ApiError> {
+///         // ...
+///     }
+/// }
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let app = app(Store);
+///     // ...
+/// }
+/// ```
+#[proc_macro]
+pub fn openapi_axum(input: TokenStream) -> TokenStream {
+    let spec = parse_macro_input!(input as LitStr);
+    let manifest_dir = std::env::var_os("CARGO_MANIFEST_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_default();
+    expand(&spec.value(), &manifest_dir, spec.span()).into()
+}
+
+/// Generates the modules for `spec`, or a `compile_error!` at `span`.
+fn expand(spec: &str, manifest_dir: &Path, span: Span) -> TokenStream2 {
+    let path = manifest_dir.join(spec);
+
+    // `include_bytes!` makes rustc recompile when the files change
+    let tracked: Vec<String> = tracked_files(&path)
+        .into_iter()
+        .filter(|file| file.exists())
+        .map(|file| file.display().to_string())
+        .collect();
+    let tracking = quote! {
+        const _: &[&[u8]] = &[#(include_bytes!(#tracked)),*];
+    };
+
+    let items = Generator::builder()
+        .spec(path.as_path())
+        .build()
+        .and_then(|generator| generator.generate())
+        .map_err(|e| syn::Error::new(span, message(spec, &e)))
+        .and_then(|project| {
+            let module = single_module(&project)?;
+            syn::parse_str::<TokenStream2>(&module)
+        })
+        .unwrap_or_else(|e| e.to_compile_error());
+
+    quote! {
+        #tracking
+        #items
+    }
+}
+
+/// `spec#pointer: detail`, locating the error in the spec when possible.
+fn message(spec: &str, error: &GeneratorError) -> String {
+    let location = match error.pointer() {
+        Some(pointer) => format!("{}#{}", spec, pointer),
+        None => spec.to_string(),
+    };
+    let detail = match error {
+        GeneratorError::Spec { source, .. } => match source.downcast_ref::<SpecError>() {
+            Some(e) => e.message.clone(),
+            None => source.to_string(),
+        },
+        _ => error.to_string(),
+    };
+    format!("{}: {}", location, detail)
+}
+
+#[cfg(test)]
+#[path = "tests/expand_test.rs"]
+mod expand_tests;
@@ -0,0 +1,52 @@
+#[cfg(test)]
+mod tests {
+    use crate::expand;
+    use proc_macro2::Span;
+    use std::fs;
+    use std::path::{Path, PathBuf};
+
+    fn test_data() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("../src/test_data")
+    }
+
+    #[test]
+    fn test_expand_petstore() {
+        let tokens = expand("petstore.json", &test_data(), Span::call_site()).to_string();
+
+        let file: syn::File = syn::parse_str(&tokens).unwrap();
+        assert!(tokens.contains("include_bytes !"));
+        assert!(tokens.contains("petstore.json"));
+        assert!(file.items.iter().any(|item| matches!(
+            item,
+            syn::Item::Mod(module) if module.ident == "models" && module.content.is_some()
+        )));
+        assert!(tokens.contains("pub trait Handlers"));
+        assert!(tokens.contains("pub fn app < H > (handlers : H)"));
+        assert!(!tokens.contains("compile_error"));
+    }
+
+    #[test]
+    fn test_expand_tracks_external_refs() {
+        let tokens = expand("ssv_openapi_bundle.yaml", &test_data(), Span::call_site()).to_string();
+        assert!(tokens.contains("healthcheck.json"));
+    }
+
+    #[test]
+    fn test_expand_reports_spec_pointer() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(
+            dir.path().join("api.yaml"),
+            "openapi: 3.0.0\ninfo:\n  title: Pets\n  version: '1'\npaths:\n  /pets:\n    get:\n      responses: []\n",
+        )
+        .unwrap();
+
+        let tokens = expand("api.yaml", dir.path(), Span::call_site()).to_string();
+        assert!(tokens.contains("compile_error ! { \"api.yaml#/paths/~1pets/get"));
+        // Still tracked, so fixing the spec recompiles
+        assert!(tokens.contains("include_bytes !"));
+
+        let tokens = expand("missing.yaml", dir.path(), Span::call_site()).to_string();
+        assert!(tokens.contains("compile_error ! { \"missing.yaml: No such file"));
+        assert!(!tokens.contains("include_bytes !"));
+    }
+}
@@ -0,0 +1,33 @@
+//! Compiles the expansion for a spec with alias components, which have no
+//! model of their own.
+
+openapi_axum_macros::openapi_axum!("../src/test_data/aliases.json");
+
+use utoipa::OpenApi;
+
+#[derive(Clone)]
+struct Store;
+
+impl categories::handlers::Handlers for Store {}
+
+impl games::handlers::Handlers for Store {}
+
+#[test]
+fn test_aliases_name_their_models() {
+    let _ = app(Store);
+    let category = models::Category {
+        name: Some("pets".to_string()),
+        parent: Some(Box::new(models::Category {
+            name: None,
+            parent: None,
+        })),
+    };
+    let _: Option<serde_json::Value> = models::Game { ball: None }.ball;
+    assert!(serde_json::to_value(category).is_ok());
+
+    let doc = serde_json::to_value(openapi::ApiDoc::openapi()).unwrap();
+    assert_eq!(
+        doc["components"]["schemas"]["ParentCategory"]["$ref"],
+        "#/components/schemas/Category"
+    );
+}
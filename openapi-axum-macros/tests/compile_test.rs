@@ -0,0 +1,61 @@
+//! Compiles the expansion for the petstore spec, including its generated
+//! tests, and serves requests through it.
+
+openapi_axum_macros::openapi_axum!("../src/test_data/petstore.json");
+
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+use axum::response::{IntoResponse, Response};
+use axum::Router;
+use tower::ServiceExt;
+
+#[derive(Clone)]
+struct Store;
+
+#[axum::async_trait]
+impl pets::handlers::Handlers for Store {
+    async fn show_pet_by_id(
+        &self,
+        path: paths::PetsPetIdPath,
+    ) -> Result<Response, errors::ApiError> {
+        Ok(axum::Json(models::Pet {
+            id: 1,
+            name: path.pet_id,
+            tag: None,
+        })
+        .into_response())
+    }
+}
+
+async fn get(app: &Router, uri: &str) -> (StatusCode, Vec<u8>) {
+    let request = Request::get(uri).body(Body::empty()).unwrap();
+    let response = app.clone().oneshot(request).await.unwrap();
+    let status = response.status();
+    let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+    (status, body.to_vec())
+}
+
+#[tokio::test]
+async fn test_routes_to_handlers() {
+    let app = app(Store);
+
+    let (status, body) = get(&app, "/pets/rex").await;
+    assert_eq!(status, StatusCode::OK);
+    let pet: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(pet["name"], "rex");
+
+    // Operations the implementation leaves out
+    let (status, _) = get(&app, "/pets?limit=3").await;
+    assert_eq!(status, StatusCode::NOT_IMPLEMENTED);
+
+    // Rejections render as the declared error body
+    let (status, body) = get(&app, "/pets?limit=many").await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    let error: models::Error = serde_json::from_slice(&body).unwrap();
+    assert_eq!(error.code, 400);
+
+    let (status, body) = get(&app, openapi::OPENAPI_JSON_PATH).await;
+    assert_eq!(status, StatusCode::OK);
+    let doc: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert!(doc["paths"]["/pets/{petId}"]["get"].is_object());
+}
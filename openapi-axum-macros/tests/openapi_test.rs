@@ -0,0 +1,14 @@
+//! Compiles the expansion for a larger spec with repeated operation names and
+//! dangling schema references, including its generated tests.
+
+openapi_axum_macros::openapi_axum!("../src/test_data/openapi.json");
+
+#[derive(Clone)]
+struct Store;
+
+impl api::handlers::Handlers for Store {}
+
+#[test]
+fn test_serves_every_operation() {
+    let _ = app(Store);
+}
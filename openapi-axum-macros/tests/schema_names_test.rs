@@ -0,0 +1,35 @@
+//! Compiles the expansion for a spec whose component names are not Rust
+//! type names, and serves its document under those names.
+
+openapi_axum_macros::openapi_axum!("../src/test_data/schema_names.json");
+
+use utoipa::OpenApi;
+
+#[derive(Clone)]
+struct Store;
+
+impl datasets::handlers::Handlers for Store {}
+
+impl filters::handlers::Handlers for Store {}
+
+#[test]
+fn test_components_keep_spec_names() {
+    let _ = app(Store);
+    let doc = serde_json::to_value(openapi::ApiDoc::openapi()).unwrap();
+
+    let schemas = &doc["components"]["schemas"];
+    assert!(schemas["dataSetList"].is_object());
+    assert!(schemas["definitions-Filter"].is_object());
+    assert!(schemas.get("DataSetList").is_none());
+
+    // References follow the components
+    assert_eq!(
+        doc["components"]["schemas"]["dataSetList"]["properties"]["filters"]["items"]["$ref"],
+        "#/components/schemas/definitions-Filter"
+    );
+    assert_eq!(
+        doc["paths"]["/datasets"]["get"]["responses"]["200"]["content"]["application/json"]
+            ["schema"]["$ref"],
+        "#/components/schemas/dataSetList"
+    );
+}
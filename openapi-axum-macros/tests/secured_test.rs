@@ -0,0 +1,62 @@
+//! Compiles the expansion for a spec with security requirements and checks
+//! the middleware answers unauthenticated requests with a challenge.
+
+openapi_axum_macros::openapi_axum!("../src/test_data/secured.json");
+
+use axum::body::Body;
+use axum::http::{header, Request, StatusCode};
+use axum::Router;
+use tower::ServiceExt;
+
+#[derive(Clone)]
+struct Store;
+
+impl health::handlers::Handlers for Store {}
+
+impl pets::handlers::Handlers for Store {}
+
+#[derive(Clone)]
+struct Tokens;
+
+#[axum::async_trait]
+impl security::Authenticator for Tokens {
+    async fn authenticate(
+        &self,
+        _scheme: security::SecurityScheme,
+        credential: &security::Credential,
+        _scopes: &[&'static str],
+    ) -> bool {
+        *credential == security::Credential::Bearer("secret".to_string())
+    }
+}
+
+async fn get(app: &Router, uri: &str, authorization: Option<&str>) -> axum::response::Response {
+    let mut request = Request::get(uri);
+    if let Some(authorization) = authorization {
+        request = request.header(header::AUTHORIZATION, authorization);
+    }
+    app.clone().oneshot(request.body(Body::empty()).unwrap()).await.unwrap()
+}
+
+#[tokio::test]
+async fn test_requires_credentials() {
+    let app = app(Store, Tokens);
+
+    let response = get(&app, "/pets/rex", None).await;
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    let challenges: Vec<_> = response
+        .headers()
+        .get_all(header::WWW_AUTHENTICATE)
+        .iter()
+        .map(|value| value.to_str().unwrap())
+        .collect();
+    assert_eq!(challenges, ["Basic", "Bearer"]);
+
+    // Let through to the unimplemented handler
+    let response = get(&app, "/pets/rex", Some("Bearer secret")).await;
+    assert_eq!(response.status(), StatusCode::NOT_IMPLEMENTED);
+
+    // Public operations are not guarded
+    let response = get(&app, "/health", None).await;
+    assert_eq!(response.status(), StatusCode::NOT_IMPLEMENTED);
+}
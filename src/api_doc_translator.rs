@@ -0,0 +1,92 @@
+use openapiv3::OpenAPI;
+use serde::Serialize;
+
+use crate::ir::types::type_name;
+use crate::ir::{Model, Operation};
+
+/// The route the generated server serves its own document on.
+pub const OPENAPI_JSON_PATH: &str = "/openapi.json";
+
+/// Describes the generated `ApiDoc` aggregating every path and component.
+#[derive(Debug, Clone, Serialize)]
+pub struct ApiDocSignature {
+    /// Paths to the `#[utoipa::path]` handlers (e.g. "crate::pets::handlers::list_pets").
+    pub paths: Vec<String>,
+
+    /// Paths to the `ToSchema` models (e.g. "crate::models::Pet").
+    pub schemas: Vec<String>,
+
+    /// The components utoipa registers under their Rust name, with the key
+    /// the spec gives them (e.g. ("DataSetList", "dataSetList")).
+    pub schema_names: Vec<(String, String)>,
+
+    /// The route serving the document.
+    pub route: String,
+
+    /// The input spec as a raw string literal, embedded so the generated code
+    /// can fill in what utoipa attributes cannot express and check the
+    /// regenerated document against it.
+    pub source_spec: String,
+}
+
+pub struct ApiDocTranslator;
+
+impl Default for ApiDocTranslator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ApiDocTranslator {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn translate(
+        &self,
+        openapi: &OpenAPI,
+        operations: &[Operation],
+        models: &[Model],
+    ) -> ApiDocSignature {
+        let paths = operations
+            .iter()
+            .map(|operation| format!("crate::{}::handlers::{}", operation.module, operation.fn_name))
+            .collect();
+
+        let schemas = models
+            .iter()
+            .map(|model| format!("crate::models::{}", model.name))
+            .collect();
+
+        // Variant payloads and request variants are not components of the spec
+        let schema_names = models
+            .iter()
+            .filter(|model| model.name == type_name(&model.spec_name))
+            .filter(|model| model.name != model.spec_name)
+            .map(|model| (model.name.clone(), model.spec_name.clone()))
+            .collect();
+
+        let spec = serde_json::to_string_pretty(openapi).unwrap_or_else(|_| "{}".to_string());
+
+        ApiDocSignature {
+            paths,
+            schemas,
+            schema_names,
+            route: OPENAPI_JSON_PATH.to_string(),
+            source_spec: raw_string_literal(&spec),
+        }
+    }
+}
+
+/// Wraps `content` in `r#"..."#` with enough hashes that it cannot terminate early.
+pub fn raw_string_literal(content: &str) -> String {
+    let mut hashes = "#".to_string();
+    while content.contains(&format!("\"{}", hashes)) {
+        hashes.push('#');
+    }
+    format!("r{hashes}\"{content}\"{hashes}")
+}
+
+#[cfg(test)]
+#[path = "tests/api_doc_translator_test.rs"]
+mod api_doc_translator_tests;
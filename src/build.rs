@@ -0,0 +1,177 @@
+//! Generates code from a build script
+//!
+//! ```ignore
+//! // build.rs
+//! fn main() {
+//!     openapi_axum_generator::build::generate("api.yaml").unwrap();
+//! }
+//!
+//! // src/main.rs, at the crate root since generated code uses `crate::` paths
+//! include!(concat!(env!("OUT_DIR"), "/openapi_axum_generator/mod.rs"));
+//! ```
+//!
+//! The crate needs the dependencies of the generated code (`axum`, `serde`,
+//! `utoipa`, ...). Cargo reruns the build script when the spec or a file it
+//! references changes.
+
+use log::debug;
+use std::collections::BTreeMap;
+use std::env;
+use std::error::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::config::GeneratorConfig;
+use crate::file_utils::external_refs;
+use crate::formatter::format_rust;
+use crate::generator::{GeneratedProject, Generator};
+
+/// The file written under `OUT_DIR`, holding every generated module.
+pub const MOD_FILE: &str = "openapi_axum_generator/mod.rs";
+
+/// Generates the server for `spec` with the default options into
+/// `OUT_DIR/openapi_axum_generator/mod.rs` and returns that path.
+pub fn generate(spec: impl AsRef<Path>) -> Result<PathBuf, Box<dyn Error>> {
+    generate_with_config(spec, GeneratorConfig::default())
+}
+
+/// Like [`generate`], with `config`.
+pub fn generate_with_config(
+    spec: impl AsRef<Path>,
+    config: GeneratorConfig,
+) -> Result<PathBuf, Box<dyn Error>> {
+    let spec = spec.as_ref();
+    // Tracked first, so fixing a broken spec triggers a rebuild
+    for file in tracked_files(spec) {
+        println!("cargo:rerun-if-changed={}", file.display());
+    }
+    let out_dir = env::var_os("OUT_DIR").ok_or("OUT_DIR is not set; call this from build.rs")?;
+    generate_into(spec, config, Path::new(&out_dir))
+}
+
+/// Generates `MOD_FILE` under `out_dir`, leaving it untouched when unchanged.
+pub(crate) fn generate_into(
+    spec: &Path,
+    config: GeneratorConfig,
+    out_dir: &Path,
+) -> Result<PathBuf, Box<dyn Error>> {
+    let project = Generator::builder().spec(spec).config(config).build()?.generate()?;
+    let content = single_module(&project)?;
+
+    let path = out_dir.join(MOD_FILE);
+    if fs::read_to_string(&path).ok().as_deref() != Some(content.as_str()) {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&path, content)?;
+    }
+    Ok(path)
+}
+
+/// The spec and the files it references.
+pub fn tracked_files(spec: &Path) -> Vec<PathBuf> {
+    let refs = external_refs(spec).unwrap_or_else(|e| {
+        debug!("Cannot follow references of {}: {}", spec.display(), e);
+        Vec::new()
+    });
+    std::iter::once(spec.to_path_buf()).chain(refs).collect()
+}
+
+/// Inlines every generated file into `src/mod.rs`, since `include!` resolves
+/// `mod name;` against the including file rather than `OUT_DIR`.
+pub fn single_module(project: &GeneratedProject) -> syn::Result<String> {
+    // Module paths such as `pets/handlers`, relative to `src/`
+    let sources: BTreeMap<String, &str> = project
+        .files
+        .iter()
+        .filter_map(|file| {
+            let module = file.path.strip_prefix("src/")?.strip_suffix(".rs")?;
+            Some((module.to_string(), file.content.as_str()))
+        })
+        .collect();
+    let root = sources.get("mod").copied().unwrap_or_default();
+    let mut file = syn::parse_file(root)?;
+    file.items = inline_items(file.items, "", &sources)?;
+    format_rust(&prettyplease::unparse(&file))
+}
+
+/// Replaces `mod name;` items with the generated module under `prefix`,
+/// dropping declarations of modules that were not generated.
+fn inline_items(
+    items: Vec<syn::Item>,
+    prefix: &str,
+    sources: &BTreeMap<String, &str>,
+) -> syn::Result<Vec<syn::Item>> {
+    let mut inlined = Vec::new();
+    for item in items {
+        let syn::Item::Mod(mut module) = item else {
+            inlined.push(item);
+            continue;
+        };
+        let path = format!("{}{}", prefix, module.ident);
+        let items = match module.content.take() {
+            Some((_, items)) => items,
+            None => match module_items(&path, sources)? {
+                Some((attrs, items)) => {
+                    module.attrs.extend(attrs);
+                    module.semi = None;
+                    items
+                }
+                None => {
+                    debug!("Dropping declaration of {}, which is not generated", path);
+                    continue;
+                }
+            },
+        };
+        module.content = Some((
+            Default::default(),
+            inline_items(items, &format!("{}/", path), sources)?,
+        ));
+        inlined.push(syn::Item::Mod(module));
+    }
+    Ok(inlined)
+}
+
+/// The inner attributes and items of the module at `path`: its own file, if
+/// generated, plus a `pub mod` for each generated file beneath it.
+fn module_items(
+    path: &str,
+    sources: &BTreeMap<String, &str>,
+) -> syn::Result<Option<(Vec<syn::Attribute>, Vec<syn::Item>)>> {
+    let own = sources
+        .get(path)
+        .or_else(|| sources.get(&format!("{}/mod", path)));
+    let mut file = match own {
+        Some(source) => syn::parse_file(source)?,
+        None => syn::parse_quote!(),
+    };
+    let children: Vec<&str> = sources
+        .keys()
+        .filter_map(|key| key.strip_prefix(path)?.strip_prefix('/'))
+        .filter_map(|rest| rest.split('/').next())
+        .filter(|child| *child != "mod")
+        .collect();
+    if own.is_none() && children.is_empty() {
+        return Ok(None);
+    }
+
+    let mut declared: Vec<String> = file
+        .items
+        .iter()
+        .filter_map(|item| match item {
+            syn::Item::Mod(module) => Some(module.ident.to_string()),
+            _ => None,
+        })
+        .collect();
+    for child in children {
+        if !declared.iter().any(|name| name == child) {
+            file.items.push(syn::parse_str(&format!("pub mod {};", child))?);
+            declared.push(child.to_string());
+        }
+    }
+    Ok(Some((file.attrs, file.items)))
+}
+
+#[cfg(test)]
+#[path = "tests/build_test.rs"]
+mod build_tests;
@@ -0,0 +1,97 @@
+//! Compares generated files with an output directory without writing
+
+use similar::TextDiff;
+use std::collections::BTreeSet;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use crate::user_code;
+use crate::writer::read_manifest;
+
+/// How a file on disk differs from what would be generated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChangeKind {
+    Created,
+    Updated,
+    Deleted,
+}
+
+/// A file that a write would change, with its unified diff.
+#[derive(Debug, Clone)]
+pub struct FileChange {
+    /// The path relative to the output directory.
+    pub path: String,
+    pub kind: ChangeKind,
+    pub diff: String,
+}
+
+impl fmt::Display for FileChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.diff)
+    }
+}
+
+/// Lists the files [`crate::writer::write_files`] would create, update or
+/// (with `remove_stale`) delete, comparing after user code regions are carried over.
+pub fn check_files(
+    output_dir: &Path,
+    files: &[(String, String)],
+    remove_stale: bool,
+) -> io::Result<Vec<FileChange>> {
+    let previous = read_manifest(output_dir)?;
+    let preserved = user_code::preserve(output_dir, files, &previous)?;
+
+    let mut changes = Vec::new();
+    for (relative, content) in &preserved.files {
+        let (kind, existing) = match fs::read_to_string(output_dir.join(relative)) {
+            Ok(existing) if existing == *content => continue,
+            Ok(existing) => (ChangeKind::Updated, existing),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => (ChangeKind::Created, String::new()),
+            Err(e) => return Err(e),
+        };
+        changes.push(FileChange {
+            path: relative.clone(),
+            kind,
+            diff: unified_diff(relative, &existing, content, kind),
+        });
+    }
+
+    if remove_stale {
+        let generated: BTreeSet<&str> = files.iter().map(|(path, _)| path.as_str()).collect();
+        for relative in previous.iter().filter(|p| !generated.contains(p.as_str())) {
+            match fs::read_to_string(output_dir.join(relative)) {
+                Ok(existing) => changes.push(FileChange {
+                    path: relative.clone(),
+                    kind: ChangeKind::Deleted,
+                    diff: unified_diff(relative, &existing, "", ChangeKind::Deleted),
+                }),
+                Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    Ok(changes)
+}
+
+fn unified_diff(path: &str, old: &str, new: &str, kind: ChangeKind) -> String {
+    let old_header = match kind {
+        ChangeKind::Created => "/dev/null".to_string(),
+        _ => format!("a/{}", path),
+    };
+    let new_header = match kind {
+        ChangeKind::Deleted => "/dev/null".to_string(),
+        _ => format!("b/{}", path),
+    };
+    TextDiff::from_lines(old, new)
+        .unified_diff()
+        .context_radius(3)
+        .header(&old_header, &new_header)
+        .to_string()
+}
+
+#[cfg(test)]
+#[path = "tests/check_test.rs"]
+mod check_tests;
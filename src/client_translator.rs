@@ -0,0 +1,253 @@
+use heck::ToUpperCamelCase;
+use openapiv3::OpenAPI;
+use serde::Serialize;
+
+use crate::errors_translator::{range_names, status_names};
+use crate::ir::keywords::{escape_rust_keyword, field_name};
+use crate::ir::{path_variables, Operation, ParameterLocation};
+
+/// Describes one client method, generated per operation.
+#[derive(Debug, Clone, Serialize)]
+pub struct ClientOperation {
+    /// The method name (e.g. "list_pets").
+    pub fn_name: String,
+
+    /// The doc comment lines, already prefixed with `///`.
+    pub doc_comment: Option<String>,
+
+    /// Whether the operation is deprecated.
+    pub deprecated: bool,
+
+    /// The HTTP method constant on `reqwest::Method` (e.g. "GET").
+    pub http_method: String,
+
+    /// The path template (e.g. "/pets/{petId}").
+    pub path: String,
+
+    /// Expressions pushed onto the URL path one segment at a time, so that
+    /// parameter values are percent-encoded (e.g. `"pets"`, `&pet_id.to_string()`).
+    pub path_segments: Vec<String>,
+
+    /// The method arguments, in path, query, header order.
+    pub args: Vec<ClientArgument>,
+
+    /// The JSON request body type, if any.
+    pub body_type: Option<String>,
+
+    /// The response enum name (e.g. "ListPetsResponse").
+    pub response_enum: String,
+
+    /// One variant per declared response.
+    pub responses: Vec<ClientResponse>,
+
+    /// Whether a `default` response is declared; otherwise undeclared statuses
+    /// are returned as `Other`.
+    pub has_default: bool,
+}
+
+/// Describes a client method argument.
+#[derive(Debug, Clone, Serialize)]
+pub struct ClientArgument {
+    /// The Rust identifier (e.g. "pet_id").
+    pub name: String,
+
+    /// The name on the wire (e.g. "petId" or "X-Request-ID").
+    pub wire_name: String,
+
+    /// The Rust type of the argument, wrapped in `Option` when not required.
+    pub rust_type: String,
+
+    /// Where the argument is sent.
+    pub location: ParameterLocation,
+
+    /// Whether the argument must be supplied.
+    pub required: bool,
+
+    /// Whether the value is a list sent as repeated query pairs.
+    pub repeated: bool,
+}
+
+/// Describes one variant of an operation's response enum.
+#[derive(Debug, Clone, Serialize)]
+pub struct ClientResponse {
+    /// The variant name (e.g. "Ok" or "NotFound").
+    pub variant: String,
+
+    /// The status code, or `None` for a range or the `default` response.
+    pub status: Option<u16>,
+
+    /// The pattern matching the statuses of a range response (e.g.
+    /// "400..=499").
+    pub range: Option<String>,
+
+    /// The JSON body type, if any.
+    pub rust_type: Option<String>,
+
+    /// Description of the response, used in doc comments.
+    pub description: Option<String>,
+}
+
+pub struct ClientTranslator;
+
+impl Default for ClientTranslator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ClientTranslator {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Builds one client method per operation.
+    pub fn translate(&self, operations: &[Operation]) -> Vec<ClientOperation> {
+        operations
+            .iter()
+            .map(|operation| self.translate_operation(operation))
+            .collect()
+    }
+
+    /// The first server URL, with its variables replaced by their defaults.
+    pub fn default_base_url(openapi: &OpenAPI) -> Option<String> {
+        openapi.servers.first().map(|server| {
+            let mut url = server.url.clone();
+            for (name, variable) in server.variables.iter().flatten() {
+                url = url.replace(&format!("{{{}}}", name), &variable.default);
+            }
+            url
+        })
+    }
+
+    fn translate_operation(&self, operation: &Operation) -> ClientOperation {
+        let mut args: Vec<ClientArgument> = Vec::new();
+        for location in [
+            ParameterLocation::Path,
+            ParameterLocation::Query,
+            ParameterLocation::Header,
+        ] {
+            for param in operation
+                .params
+                .iter()
+                .filter(|param| param.location == location)
+            {
+                // Path parameters are always required
+                let required = param.required || matches!(location, ParameterLocation::Path);
+                args.push(ClientArgument {
+                    name: param.rust_name.clone(),
+                    wire_name: param.name.clone(),
+                    rust_type: if required {
+                        param.rust_type.clone()
+                    } else {
+                        format!("Option<{}>", param.rust_type)
+                    },
+                    location: location.clone(),
+                    required,
+                    repeated: param.rust_type.starts_with("Vec<"),
+                });
+            }
+        }
+
+        // Path template variables the spec forgot to declare are still needed to build the URL
+        for variable in path_variables(&operation.path) {
+            if !args.iter().any(|arg| arg.wire_name == variable) {
+                args.insert(
+                    0,
+                    ClientArgument {
+                        name: field_name(variable),
+                        wire_name: variable.to_string(),
+                        rust_type: "String".to_string(),
+                        location: ParameterLocation::Path,
+                        required: true,
+                        repeated: false,
+                    },
+                );
+            }
+        }
+
+        let path_segments = operation
+            .path
+            .split('/')
+            .filter(|segment| !segment.is_empty())
+            .map(|segment| Self::segment_expr(segment, &args))
+            .collect();
+
+        let responses: Vec<ClientResponse> = operation
+            .responses
+            .iter()
+            .map(|response| {
+                let (variant, status, range) = match (response.status, response.range) {
+                    (_, Some(range)) => {
+                        let (variant, pattern) = range_names(range);
+                        (variant, None, Some(pattern))
+                    }
+                    (Some(status), None) => (status_names(status).0, Some(status), None),
+                    (None, None) => ("Default".to_string(), None, None),
+                };
+                ClientResponse {
+                    variant,
+                    status,
+                    range,
+                    rust_type: response.rust_type.clone(),
+                    description: response.description.clone(),
+                }
+            })
+            .collect();
+        let has_default = operation
+            .responses
+            .iter()
+            .any(|response| response.status.is_none());
+
+        ClientOperation {
+            fn_name: escape_rust_keyword(&operation.fn_name),
+            doc_comment: operation.doc_comment.clone(),
+            deprecated: operation.deprecated,
+            http_method: operation.http_method.to_uppercase(),
+            path: operation.path.clone(),
+            path_segments,
+            args,
+            body_type: operation
+                .request_body
+                .as_ref()
+                .map(|body| body.rust_type.clone()),
+            response_enum: format!("{}Response", operation.fn_name.to_upper_camel_case()),
+            responses,
+            has_default,
+        }
+    }
+
+    /// Turns one path segment into the expression pushed onto the URL.
+    fn segment_expr(segment: &str, args: &[ClientArgument]) -> String {
+        if !segment.contains('{') {
+            return format!("{:?}", segment);
+        }
+        let mut format_string = String::new();
+        let mut format_args = Vec::new();
+        let mut rest = segment;
+        while let Some(start) = rest.find('{') {
+            let Some(end) = rest[start..].find('}') else {
+                break;
+            };
+            format_string.push_str(&rest[..start]);
+            format_string.push_str("{}");
+            let wire_name = &rest[start + 1..start + end];
+            let name = args
+                .iter()
+                .find(|arg| arg.wire_name == wire_name)
+                .map(|arg| arg.name.clone())
+                .unwrap_or_else(|| field_name(wire_name));
+            format_args.push(name);
+            rest = &rest[start + end + 1..];
+        }
+        format_string.push_str(rest);
+        if format_string == "{}" {
+            format!("&{}.to_string()", format_args[0])
+        } else {
+            format!("&format!({:?}, {})", format_string, format_args.join(", "))
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/client_translator_test.rs"]
+mod client_translator_tests;
@@ -0,0 +1,164 @@
+//! Options controlling what the generator emits
+
+use std::str::FromStr;
+
+use serde::Serialize;
+
+/// The shape of the error bodies rendered by the generated `ApiError`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub enum ErrorStyle {
+    /// Use the error schemas declared in the spec (e.g. petstore `Error`).
+    #[default]
+    Declared,
+    /// Render every error as an RFC 7807 `application/problem+json` document.
+    Problem,
+}
+
+impl FromStr for ErrorStyle {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "declared" => Ok(Self::Declared),
+            "problem" => Ok(Self::Problem),
+            other => Err(format!("unknown error style: {}", other)),
+        }
+    }
+}
+
+/// What the generator emits.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub enum Target {
+    /// Axum handlers, router and models.
+    #[default]
+    Server,
+    /// A `reqwest` client sharing the same models.
+    Client,
+}
+
+impl FromStr for Target {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "server" => Ok(Self::Server),
+            "client" => Ok(Self::Client),
+            other => Err(format!("unknown target: {}", other)),
+        }
+    }
+}
+
+/// The interactive documentation UI served by the generated docs module.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub enum DocsUi {
+    /// Swagger UI (`swagger-ui-dist`).
+    #[default]
+    SwaggerUi,
+    /// ReDoc (`redoc`).
+    Redoc,
+    /// Scalar (`@scalar/api-reference`).
+    Scalar,
+}
+
+impl FromStr for DocsUi {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "swagger-ui" => Ok(Self::SwaggerUi),
+            "redoc" => Ok(Self::Redoc),
+            "scalar" => Ok(Self::Scalar),
+            other => Err(format!("unknown docs UI: {}", other)),
+        }
+    }
+}
+
+/// Which document the docs UI renders.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub enum DocsSpec {
+    /// The `ApiDoc` regenerated from the handlers and models.
+    #[default]
+    Generated,
+    /// The input spec, verbatim.
+    Original,
+}
+
+impl FromStr for DocsSpec {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "generated" => Ok(Self::Generated),
+            "original" => Ok(Self::Original),
+            other => Err(format!("unknown docs spec: {}", other)),
+        }
+    }
+}
+
+/// The map generated for `additionalProperties`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub enum MapType {
+    /// `std::collections::HashMap`.
+    #[default]
+    HashMap,
+    /// `std::collections::BTreeMap`, which serializes keys in order.
+    BTreeMap,
+}
+
+impl FromStr for MapType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "hash-map" => Ok(Self::HashMap),
+            "btree-map" => Ok(Self::BTreeMap),
+            other => Err(format!("unknown map type: {}", other)),
+        }
+    }
+}
+
+/// Options for the generated interactive documentation.
+#[derive(Debug, Clone)]
+pub struct DocsConfig {
+    /// The UI to serve.
+    pub ui: DocsUi,
+
+    /// Where the UI is mounted in the router (e.g. "/docs").
+    pub path: String,
+
+    /// Which document the UI renders.
+    pub spec: DocsSpec,
+
+    /// Directory, relative to the generated crate's manifest, holding the
+    /// vendored UI assets to embed with `include_bytes!`. Without one the
+    /// page loads the assets from a CDN.
+    pub assets_dir: Option<String>,
+}
+
+impl Default for DocsConfig {
+    fn default() -> Self {
+        Self {
+            ui: DocsUi::default(),
+            path: "/docs".to_string(),
+            spec: DocsSpec::default(),
+            assets_dir: None,
+        }
+    }
+}
+
+/// Options for a generator run. `Default` reproduces the generator's
+/// original output.
+#[derive(Debug, Clone, Default)]
+pub struct GeneratorConfig {
+    /// How error responses are rendered.
+    pub error_style: ErrorStyle,
+
+    /// Whether to emit the server or a client.
+    pub target: Target,
+
+    /// Interactive documentation to generate, if any.
+    pub docs: Option<DocsConfig>,
+
+    /// The map type of `additionalProperties`.
+    pub map_type: MapType,
+}
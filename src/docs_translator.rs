@@ -0,0 +1,160 @@
+use heck::ToShoutySnakeCase;
+use openapiv3::OpenAPI;
+use serde::Serialize;
+
+use crate::api_doc_translator::OPENAPI_JSON_PATH;
+use crate::config::{DocsConfig, DocsSpec, DocsUi};
+
+/// Describes the generated docs module.
+#[derive(Debug, Clone, Serialize)]
+pub struct DocsSignature {
+    /// The UI to serve.
+    pub ui: DocsUi,
+
+    /// The page title, HTML-escaped.
+    pub title: String,
+
+    /// The route of the HTML page (e.g. "/docs").
+    pub page_route: String,
+
+    /// The route the UI loads the document from: the `openapi` module's route
+    /// for the generated document, or e.g. "/docs/source.json" for the input
+    /// spec, which only the docs router serves.
+    pub spec_route: String,
+
+    /// Whether the input spec is served instead of the regenerated `ApiDoc`.
+    pub original_spec: bool,
+
+    /// Whether the assets are embedded and served by the docs router, rather
+    /// than loaded from their CDN URLs.
+    pub embedded: bool,
+
+    /// The asset files the page loads.
+    pub assets: Vec<DocsAsset>,
+}
+
+/// A UI asset, either vendored and embedded with `include_bytes!` or loaded
+/// from its CDN URL.
+#[derive(Debug, Clone, Serialize)]
+pub struct DocsAsset {
+    /// The file name, as shipped by the UI's npm package (e.g. "redoc.standalone.js").
+    pub file: String,
+
+    /// The path of the file relative to the generated crate's manifest, for
+    /// embedded assets.
+    pub source: Option<String>,
+
+    /// Where the file can be downloaded from, by the page or to vendor it.
+    pub url: String,
+
+    /// Where the page loads the file from: the route serving an embedded file
+    /// (e.g. "/docs/redoc.standalone.js"), or else its URL.
+    pub route: String,
+
+    /// The constant holding the bytes (e.g. "REDOC_STANDALONE_JS").
+    pub const_name: String,
+
+    /// The `Content-Type` the file is served with.
+    pub content_type: String,
+}
+
+pub struct DocsTranslator;
+
+impl Default for DocsTranslator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DocsTranslator {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn translate(&self, openapi: &OpenAPI, config: &DocsConfig) -> DocsSignature {
+        let mount = normalize_mount_path(&config.path);
+        let page_route = if mount.is_empty() { "/".to_string() } else { mount.clone() };
+        let assets_dir = config
+            .assets_dir
+            .as_deref()
+            .map(|dir| dir.trim_end_matches('/'));
+
+        let assets = Self::asset_files(config.ui)
+            .iter()
+            .map(|(file, url)| DocsAsset {
+                file: file.to_string(),
+                source: assets_dir.map(|dir| format!("{}/{}", dir, file)),
+                url: url.to_string(),
+                route: match assets_dir {
+                    Some(_) => format!("{}/{}", mount, file),
+                    None => url.to_string(),
+                },
+                const_name: file.replace(['.', '-'], "_").to_shouty_snake_case(),
+                content_type: if file.ends_with(".css") {
+                    "text/css".to_string()
+                } else {
+                    "application/javascript".to_string()
+                },
+            })
+            .collect();
+
+        DocsSignature {
+            ui: config.ui,
+            title: html_escape(&openapi.info.title),
+            page_route,
+            spec_route: match config.spec {
+                DocsSpec::Generated => OPENAPI_JSON_PATH.to_string(),
+                DocsSpec::Original => format!("{}/source.json", mount),
+            },
+            original_spec: config.spec == DocsSpec::Original,
+            embedded: assets_dir.is_some(),
+            assets,
+        }
+    }
+
+    /// The files each UI needs, as found in its npm package, with where to
+    /// download them.
+    pub fn asset_files(ui: DocsUi) -> &'static [(&'static str, &'static str)] {
+        match ui {
+            DocsUi::SwaggerUi => &[
+                (
+                    "swagger-ui.css",
+                    "https://unpkg.com/swagger-ui-dist@5/swagger-ui.css",
+                ),
+                (
+                    "swagger-ui-bundle.js",
+                    "https://unpkg.com/swagger-ui-dist@5/swagger-ui-bundle.js",
+                ),
+            ],
+            DocsUi::Redoc => &[(
+                "redoc.standalone.js",
+                "https://unpkg.com/redoc@2/bundles/redoc.standalone.js",
+            )],
+            DocsUi::Scalar => &[(
+                "standalone.js",
+                "https://unpkg.com/@scalar/api-reference@1/dist/browser/standalone.js",
+            )],
+        }
+    }
+}
+
+/// Turns "docs/", "/docs/" or "/docs" into "/docs", and "/" into "".
+fn normalize_mount_path(path: &str) -> String {
+    let trimmed = path.trim_matches('/');
+    if trimmed.is_empty() {
+        String::new()
+    } else {
+        format!("/{}", trimmed)
+    }
+}
+
+fn html_escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+#[path = "tests/docs_translator_test.rs"]
+mod docs_translator_tests;
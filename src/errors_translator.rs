@@ -0,0 +1,267 @@
+use axum::http::StatusCode as HttpStatusCode;
+use heck::{ToShoutySnakeCase, ToUpperCamelCase};
+use serde::Serialize;
+
+use crate::config::ErrorStyle;
+use crate::ir::keywords::unraw;
+use crate::ir::types::UNTYPED;
+use crate::ir::{Model, Operation, Response};
+
+/// Describes one variant of the generated `ApiError` enum.
+#[derive(Debug, Clone, Serialize)]
+pub struct ErrorVariantSignature {
+    /// The enum variant name (e.g. "NotFound").
+    pub variant: String,
+
+    /// The concrete status code, or `None` for `4XX`/`5XX` ranges and `default`,
+    /// whose status is carried by the variant at runtime.
+    pub status: Option<u16>,
+
+    /// The `StatusCode` expression for a concrete status (e.g. "StatusCode::NOT_FOUND").
+    pub status_expr: Option<String>,
+
+    /// The match pattern selecting this variant from a status code (e.g. "404" or "400..=499").
+    pub status_pattern: String,
+
+    /// Description of the response, used in doc comments.
+    pub description: Option<String>,
+
+    /// The declared response body, if any.
+    pub body: Option<ErrorBodySignature>,
+}
+
+/// Describes the body of an error response.
+#[derive(Debug, Clone, Serialize)]
+pub struct ErrorBodySignature {
+    /// The Rust type of the body (e.g. "Error").
+    pub rust_type: String,
+
+    /// An expression building the body from `status` and `message`, used to
+    /// render extractor rejections in the declared shape.
+    pub constructor: String,
+}
+
+pub struct ErrorsTranslator {
+    error_style: ErrorStyle,
+}
+
+impl Default for ErrorsTranslator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ErrorsTranslator {
+    pub fn new() -> Self {
+        Self::with_style(ErrorStyle::Declared)
+    }
+
+    pub fn with_style(error_style: ErrorStyle) -> Self {
+        Self { error_style }
+    }
+
+    /// Collects the error responses (4XX/5XX and `default`) declared across all
+    /// operations into `ApiError` variants. The last variant is always the
+    /// catch-all `Default`.
+    pub fn translate(&self, operations: &[Operation], models: &[Model]) -> Vec<ErrorVariantSignature> {
+        let mut codes: Vec<(u16, &Response)> = Vec::new();
+        let mut ranges: Vec<(u16, &Response)> = Vec::new();
+        let mut default: Option<&Response> = None;
+
+        for response in operations.iter().flat_map(|operation| &operation.responses) {
+            match (response.status, response.range) {
+                (_, Some(range)) if range >= 4 && !ranges.iter().any(|(r, _)| *r == range) => {
+                    ranges.push((range, response));
+                }
+                (Some(code), None) if code >= 400 && !codes.iter().any(|(c, _)| *c == code) => {
+                    codes.push((code, response));
+                }
+                (None, None) if default.is_none() => default = Some(response),
+                _ => {}
+            }
+        }
+        codes.sort_by_key(|(code, _)| *code);
+        ranges.sort_by_key(|(range, _)| *range);
+
+        let mut variants: Vec<ErrorVariantSignature> = codes
+            .iter()
+            .map(|(code, response)| {
+                let (variant, status_expr) = status_names(*code);
+                ErrorVariantSignature {
+                    variant,
+                    status: Some(*code),
+                    status_expr: Some(status_expr),
+                    status_pattern: code.to_string(),
+                    description: response.description.clone(),
+                    body: Self::error_body(response, models),
+                }
+            })
+            .collect();
+
+        variants.extend(ranges.iter().map(|(range, response)| {
+            let (variant, status_pattern) = range_names(*range);
+            ErrorVariantSignature {
+                variant,
+                status: None,
+                status_expr: None,
+                status_pattern,
+                description: response.description.clone(),
+                body: Self::error_body(response, models),
+            }
+        }));
+
+        // Everything else falls back to the `default` response, or failing that
+        // to the most recently declared error body so rejections keep one shape.
+        let fallback_body = default
+            .as_ref()
+            .and_then(|response| Self::error_body(response, models))
+            .or_else(|| variants.iter().rev().find_map(|v| v.body.clone()));
+        variants.push(ErrorVariantSignature {
+            variant: "Default".to_string(),
+            status: None,
+            status_expr: None,
+            status_pattern: "_".to_string(),
+            description: default.and_then(|response| response.description.clone()),
+            body: fallback_body,
+        });
+
+        // Problem details replace whatever bodies the spec declared
+        if self.error_style == ErrorStyle::Problem {
+            for variant in &mut variants {
+                variant.body = Some(ErrorBodySignature {
+                    rust_type: "Problem".to_string(),
+                    constructor: "Problem::new(status).with_detail(message.clone())".to_string(),
+                });
+            }
+        }
+
+        variants
+    }
+
+    fn error_body(response: &Response, models: &[Model]) -> Option<ErrorBodySignature> {
+        let rust_type = response.rust_type.as_ref()?;
+
+        // Only bodies that became models get a typed variant
+        let model = models.iter().find(|model| &model.name == rust_type);
+        // A required field with no sensible value leaves the body untyped
+        let fields = model
+            .filter(|model| !model.fields.is_empty())
+            .and_then(|model| {
+                model
+                    .fields
+                    .iter()
+                    .map(|field| {
+                        Self::field_value(&field.name, &field.rust_type)
+                            .map(|value| format!("{}: {}", field.name, value))
+                    })
+                    .collect::<Option<Vec<_>>>()
+                    .map(|fields| (model, fields))
+            });
+        let (rust_type, constructor) = match fields {
+            Some((model, fields)) => (
+                model.name.clone(),
+                format!("{} {{ {} }}", model.name, fields.join(", ")),
+            ),
+            None => (
+                "serde_json::Value".to_string(),
+                "serde_json::json!({ \"code\": status.as_u16(), \"message\": message })"
+                    .to_string(),
+            ),
+        };
+
+        Some(ErrorBodySignature {
+            rust_type,
+            constructor,
+        })
+    }
+
+    /// Picks the value for an error body field: the status for code-like
+    /// integer fields, the message for text fields, otherwise `None` or the
+    /// default. `None` when the field is required and its type has no default.
+    fn field_value(name: &str, rust_type: &str) -> Option<String> {
+        let name = unraw(name).replace('_', "");
+        let (inner, optional) = match rust_type.strip_prefix("Option<") {
+            Some(inner) => (inner.trim_end_matches('>'), true),
+            None => (rust_type, false),
+        };
+        let value = if ["code", "status", "statuscode"].contains(&name.as_str())
+            && (inner.starts_with('i') || inner.starts_with('u'))
+        {
+            "status.as_u16().into()"
+        } else if ["message", "detail", "error", "title", "description", "msg"]
+            .contains(&name.as_str())
+            && inner == "String"
+        {
+            "message.clone()"
+        } else if optional {
+            return Some("None".to_string());
+        } else if has_default(rust_type) {
+            return Some("Default::default()".to_string());
+        } else {
+            return None;
+        };
+        if optional {
+            Some(format!("Some({})", value))
+        } else {
+            Some(value.to_string())
+        }
+    }
+}
+
+/// Whether `rust_type`, as mapped by the IR, implements `Default`. Models
+/// are assumed not to.
+fn has_default(rust_type: &str) -> bool {
+    const SCALARS: &[&str] = &["String", "bool", "i32", "i64", "f32", "f64", UNTYPED];
+    const CONTAINERS: &[&str] = &[
+        "Option<",
+        "Vec<",
+        "std::collections::HashMap<",
+        "std::collections::BTreeMap<",
+    ];
+    if let Some(inner) = rust_type.strip_prefix("Box<") {
+        return has_default(inner.strip_suffix('>').unwrap_or(inner));
+    }
+    SCALARS.contains(&rust_type)
+        || CONTAINERS
+            .iter()
+            .any(|prefix| rust_type.starts_with(prefix))
+}
+
+/// Names a status code after its canonical reason, returning the enum variant
+/// (e.g. "NotFound") and the `StatusCode` expression (e.g. "StatusCode::NOT_FOUND").
+pub fn status_names(code: u16) -> (String, String) {
+    match HttpStatusCode::from_u16(code)
+        .ok()
+        .and_then(|status| status.canonical_reason())
+        .filter(|reason| {
+            reason
+                .chars()
+                .all(|c| c.is_alphanumeric() || c == ' ' || c == '-')
+        }) {
+        Some(reason) => (
+            reason.to_upper_camel_case(),
+            format!("StatusCode::{}", reason.to_shouty_snake_case()),
+        ),
+        None => (
+            format!("Status{}", code),
+            format!("StatusCode::from_u16({}).unwrap()", code),
+        ),
+    }
+}
+
+/// The variant name and the match pattern of a status class (e.g. 4 ->
+/// ("ClientError", "400..=499")).
+pub fn range_names(range: u16) -> (String, String) {
+    let variant = match range {
+        1 => "Informational",
+        2 => "Success",
+        3 => "Redirection",
+        4 => "ClientError",
+        _ => "ServerError",
+    };
+    (variant.to_string(), format!("{}00..={}99", range, range))
+}
+
+#[cfg(test)]
+#[path = "tests/errors_translator_test.rs"]
+mod errors_translator_tests;
@@ -2,11 +2,14 @@
 
 use axum::http::status;
 use openapiv3::OpenAPI;
+use serde_path_to_error::Segment;
+use std::collections::BTreeSet;
 use std::error::Error;
+use std::fmt;
 use std::fs;
-use std::path::Path;
-use serde_json::from_str;
-use serde_yaml::from_reader;
+use std::path::{Path, PathBuf};
+use serde::Deserialize;
+use serde_json::{from_str, Value};
 
 /// Reads an OpenAPI specification from a file
 /// 
@@ -16,18 +19,182 @@ use serde_yaml::from_reader;
 /// # Returns
 /// Result containing the parsed OpenAPI specification or an error
 pub fn openapi_from_file<P: AsRef<Path>>(input_file: P) -> Result<OpenAPI, Box<dyn Error>> {
-    let path = input_file.as_ref();
-    let spec_str = fs::read_to_string(path)?;
-    
+    let spec_str = fs::read_to_string(input_file.as_ref())?;
+    Ok(openapi_from_str(&spec_str)?)
+}
+
+/// A specification that does not parse or does not match the OpenAPI schema
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpecError {
+    /// JSON pointer to the offending value, when the document parsed
+    pub pointer: Option<String>,
+    pub message: String,
+}
+
+impl fmt::Display for SpecError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.pointer {
+            Some(pointer) => write!(f, "at #{}: {}", pointer, self.message),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+impl Error for SpecError {}
+
+/// Parses a JSON or YAML OpenAPI specification
+///
+/// # Returns
+/// The parsed specification, or an error locating the first value that does
+/// not match the OpenAPI schema
+pub fn openapi_from_str(spec_str: &str) -> Result<OpenAPI, SpecError> {
     // Try parsing as JSON first, then fall back to YAML
-    if let Ok(spec) = from_str::<OpenAPI>(&spec_str) {
+    if let Ok(spec) = from_str::<OpenAPI>(spec_str) {
         return Ok(spec);
     }
-    
-    // If JSON parsing failed, try YAML
-    let file = fs::File::open(path)?;
-    let spec = from_reader(file)?;
-    Ok(spec)
+    // YAML also accepts JSON with trailing commas
+    let value: Value = match from_str(spec_str) {
+        Ok(value) => value,
+        Err(json_error) => match serde_yaml::from_str::<serde_yaml::Value>(spec_str) {
+            Ok(value) => serde_json::to_value(value).map_err(|e| syntax_error(e.to_string()))?,
+            Err(_) if spec_str.trim_start().starts_with('{') => {
+                return Err(syntax_error(json_error.to_string()))
+            }
+            Err(yaml_error) => return Err(syntax_error(yaml_error.to_string())),
+        },
+    };
+    let error = match serde_path_to_error::deserialize::<_, OpenAPI>(&value) {
+        Ok(spec) => return Ok(spec),
+        Err(e) => e,
+    };
+    let message = error.inner().to_string();
+    let pointer = narrow_pointer(&value, path_to_pointer(error.path()), &message);
+    Err(SpecError { pointer: Some(pointer), message })
+}
+
+fn syntax_error(message: String) -> SpecError {
+    SpecError { pointer: None, message }
+}
+
+/// Narrows `pointer` down to the value causing `message`.
+///
+/// Paths are lost below the `#[serde(flatten)]` extensions of most openapiv3
+/// types, so descend into whichever child's removal changes the error. How
+/// far that gets below an untagged `ReferenceOr` depends on how serde words
+/// its errors, so the result is only guaranteed to start at the referencing
+/// item (an operation, a schema, ...).
+fn narrow_pointer(root: &Value, mut pointer: String, message: &str) -> String {
+    // Children are taken out of and put back into a single scratch copy
+    let mut scratch = root.clone();
+    'descend: loop {
+        let keys: Vec<String> = match scratch.pointer(&pointer) {
+            Some(Value::Object(map)) => map.keys().cloned().collect(),
+            Some(Value::Array(items)) => (0..items.len()).map(|i| i.to_string()).collect(),
+            _ => return pointer,
+        };
+        for key in keys {
+            let removed = match scratch.pointer_mut(&pointer) {
+                Some(Value::Object(map)) => {
+                    let index = map.keys().position(|k| *k == key).unwrap_or_default();
+                    map.shift_remove_entry(&key)
+                        .map(|(key, child)| (index, key, child))
+                }
+                Some(Value::Array(items)) => {
+                    let index: usize = key.parse().unwrap_or_default();
+                    Some((index, key.clone(), items.remove(index)))
+                }
+                _ => return pointer,
+            };
+            let changed = match OpenAPI::deserialize(&scratch) {
+                Ok(_) => true,
+                Err(e) => e.to_string() != message,
+            };
+            match (scratch.pointer_mut(&pointer), removed) {
+                (Some(Value::Object(map)), Some((index, key, child))) => {
+                    map.shift_insert(index, key, child);
+                }
+                (Some(Value::Array(items)), Some((index, _, child))) => {
+                    items.insert(index, child);
+                }
+                _ => {}
+            }
+            if changed {
+                pointer.push_str(&json_pointer([key.as_str()]));
+                continue 'descend;
+            }
+        }
+        return pointer;
+    }
+}
+
+/// Joins `segments` into a JSON pointer, escaping `~` and `/`
+pub fn json_pointer<'a>(segments: impl IntoIterator<Item = &'a str>) -> String {
+    segments
+        .into_iter()
+        .map(|segment| format!("/{}", segment.replace('~', "~0").replace('/', "~1")))
+        .collect()
+}
+
+fn path_to_pointer(path: &serde_path_to_error::Path) -> String {
+    let segments: Vec<String> = path
+        .iter()
+        .filter_map(|segment| match segment {
+            Segment::Seq { index } => Some(index.to_string()),
+            Segment::Map { key } => Some(key.clone()),
+            Segment::Enum { variant } => Some(variant.clone()),
+            Segment::Unknown => None,
+        })
+        .collect();
+    json_pointer(segments.iter().map(String::as_str))
+}
+
+/// Lists the local files a specification references through `$ref`,
+/// following references in those files too
+///
+/// # Arguments
+/// * `input_file` - Path to the OpenAPI specification file
+///
+/// # Returns
+/// The referenced files, resolved relative to the file referencing them,
+/// excluding `input_file` itself and remote (`http://...`) references
+pub fn external_refs<P: AsRef<Path>>(input_file: P) -> Result<Vec<PathBuf>, Box<dyn Error>> {
+    let root = input_file.as_ref().to_path_buf();
+    let mut found = BTreeSet::new();
+    let mut pending = vec![root.clone()];
+    while let Some(file) = pending.pop() {
+        let content = fs::read_to_string(&file)?;
+        let value: Value = match from_str(&content) {
+            Ok(value) => value,
+            Err(_) => serde_yaml::from_str(&content)?,
+        };
+        let base = file.parent().unwrap_or_else(|| Path::new(""));
+        let mut refs = Vec::new();
+        collect_refs(&value, &mut refs);
+        for reference in refs {
+            let target = reference.split('#').next().unwrap_or_default();
+            if target.is_empty() || target.contains("://") {
+                continue;
+            }
+            let path = base.join(target);
+            if path != root && found.insert(path.clone()) && path.exists() {
+                pending.push(path);
+            }
+        }
+    }
+    Ok(found.into_iter().collect())
+}
+
+fn collect_refs<'a>(value: &'a Value, refs: &mut Vec<&'a str>) {
+    match value {
+        Value::Object(map) => {
+            if let Some(Value::String(reference)) = map.get("$ref") {
+                refs.push(reference);
+            }
+            map.values().for_each(|v| collect_refs(v, refs));
+        }
+        Value::Array(items) => items.iter().for_each(|v| collect_refs(v, refs)),
+        _ => {}
+    }
 }
 
 /// Creates a minimal OpenAPI specification for testing
@@ -83,6 +250,31 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_external_refs() {
+        let refs = external_refs("src/test_data/ssv_openapi_bundle.yaml").unwrap();
+        assert!(refs.contains(&PathBuf::from("src/test_data/./healthcheck.json")));
+        assert!(refs.contains(&PathBuf::from("src/test_data/./validators.json")));
+        assert!(refs.iter().all(|path| path.extension().unwrap() == "json"));
+
+        assert!(external_refs("src/test_data/petstore.json").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_openapi_from_str_locates_errors() {
+        let yaml = "openapi: 3.0.0\ninfo:\n  title: Pets\n  version: '1'\npaths:\n  /pets/{id}:\n    get:\n      responses: 200\n";
+        let error = openapi_from_str(yaml).unwrap_err();
+        // How far below the untagged `ReferenceOr` this gets depends on serde
+        assert!(error.pointer.as_deref().unwrap().starts_with("/paths/~1pets~1{id}/get"));
+        assert!(error.to_string().starts_with("at #/paths/~1pets~1{id}/get"));
+
+        let json = r#"{"openapi": "3.0.0", "info": {"title": "Pets", "version": 1}, "paths": {}}"#;
+        assert_eq!(openapi_from_str(json).unwrap_err().pointer.as_deref(), Some("/info/version"));
+
+        // Syntax errors have no pointer
+        assert_eq!(openapi_from_str("openapi: [").unwrap_err().pointer, None);
+    }
+
     #[test]
     fn test_create_minimal_openapi() {
         let spec = create_minimal_openapi();
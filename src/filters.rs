@@ -23,3 +23,8 @@ pub fn sanitize_handler_name(input: &str) -> Result<String> {
         .replace("}", "")
         .to_lowercase())
 }
+
+/// `value` as a Rust string literal, quotes and backslashes escaped.
+pub fn rust_string<T: std::fmt::Display>(value: T) -> Result<String> {
+    Ok(format!("{:?}", value.to_string()))
+}
@@ -0,0 +1,97 @@
+//! Formats generated Rust in-process with `syn` and `prettyplease`
+
+use crate::user_code;
+
+const BEGIN_MACRO: &str = "__user_code_begin!";
+const END_MACRO: &str = "__user_code_end!();";
+
+/// Parses `source` as a Rust file and pretty-prints it.
+///
+/// Ordinary `//` comments do not survive parsing, so user code markers are
+/// carried through as placeholder macro invocations and restored afterwards.
+pub fn format_rust(source: &str) -> syn::Result<String> {
+    let file = syn::parse_file(&protect_markers(source))?;
+    Ok(separate_items(&restore_markers(&prettyplease::unparse(&file))))
+}
+
+/// Turns user code markers into statements `syn` keeps, line for line, so
+/// error positions still match `source`.
+fn protect_markers(source: &str) -> String {
+    source
+        .split_inclusive('\n')
+        .map(|line| {
+            let (content, newline) = match line.strip_suffix('\n') {
+                Some(content) => (content, "\n"),
+                None => (line, ""),
+            };
+            if let Some(name) = user_code::region_start(content) {
+                format!("{}({:?});{}", BEGIN_MACRO, name, newline)
+            } else if user_code::is_region_end(content) {
+                format!("{}{}", END_MACRO, newline)
+            } else {
+                line.to_string()
+            }
+        })
+        .collect()
+}
+
+fn restore_markers(formatted: &str) -> String {
+    formatted
+        .split_inclusive('\n')
+        .map(|line| {
+            let trimmed = line.trim_start();
+            let indent = &line[..line.len() - trimmed.len()];
+            if let Some(name) = trimmed
+                .strip_prefix(BEGIN_MACRO)
+                .and_then(|rest| rest.trim_end().strip_prefix("(\""))
+                .and_then(|rest| rest.strip_suffix("\");"))
+            {
+                format!("{}// <user-code name=\"{}\">\n", indent, name)
+            } else if trimmed.trim_end() == END_MACRO {
+                format!("{}{}\n", indent, user_code::REGION_END)
+            } else {
+                line.to_string()
+            }
+        })
+        .collect()
+}
+
+const ITEM_STARTS: &[&str] = &[
+    "///", "//!", "// <user-code", "#[", "#![", "pub ", "fn ", "async fn ", "impl", "struct ",
+    "enum ", "mod ", "const ", "static ", "type ", "trait ", "use ", "unsafe ",
+];
+
+/// Puts a blank line between items, which `prettyplease` prints back to back.
+/// Runs of `use` or `mod` declarations stay together.
+fn separate_items(formatted: &str) -> String {
+    let mut separated = String::with_capacity(formatted.len());
+    let mut previous: Option<&str> = None;
+    for line in formatted.split_inclusive('\n') {
+        if let Some(prev) = previous {
+            let indent = line.len() - line.trim_start().len();
+            let prev_indent = prev.len() - prev.trim_start().len();
+            let (trimmed, prev_trimmed) = (line.trim(), prev.trim());
+            let starts_item = ITEM_STARTS.iter().any(|start| trimmed.starts_with(start));
+            let ends_item = match prev_trimmed {
+                "}" | "};" | user_code::REGION_END => true,
+                item if item.ends_with(';') => {
+                    prev_indent == 0 || ITEM_STARTS.iter().any(|start| item.starts_with(start))
+                }
+                _ => false,
+            };
+            let same_group = ["use ", "pub use ", "mod ", "pub mod "]
+                .iter()
+                .any(|group| trimmed.starts_with(group) && prev_trimmed.starts_with(group));
+            if indent == prev_indent && starts_item && ends_item && !same_group {
+                separated.push('\n');
+            }
+        }
+        separated.push_str(line);
+        previous = Some(line);
+    }
+    separated
+}
+
+#[cfg(test)]
+#[path = "tests/formatter_test.rs"]
+mod formatter_tests;
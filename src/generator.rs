@@ -0,0 +1,300 @@
+//! The library entry point: `Generator::builder().spec(..).build()?.generate()`
+
+use askama::Template;
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use openapiv3::OpenAPI;
+
+use crate::check::{self, FileChange};
+use crate::config::{GeneratorConfig, Target};
+use crate::file_utils::{openapi_from_str, SpecError};
+use crate::ir::Diagnostic;
+use crate::validate::{InvalidRustError, Origin};
+use crate::writer::{self, WriteReport};
+use crate::AxumTemplate;
+
+/// Where the spec comes from: a file (JSON or YAML) or a parsed document.
+#[derive(Debug, Clone)]
+pub enum Spec {
+    File(PathBuf),
+    Document(Box<OpenAPI>),
+}
+
+impl From<OpenAPI> for Spec {
+    fn from(openapi: OpenAPI) -> Self {
+        Self::Document(Box::new(openapi))
+    }
+}
+
+impl From<&OpenAPI> for Spec {
+    fn from(openapi: &OpenAPI) -> Self {
+        Self::Document(Box::new(openapi.clone()))
+    }
+}
+
+impl From<PathBuf> for Spec {
+    fn from(path: PathBuf) -> Self {
+        Self::File(path)
+    }
+}
+
+impl From<&Path> for Spec {
+    fn from(path: &Path) -> Self {
+        Self::File(path.to_path_buf())
+    }
+}
+
+impl From<&str> for Spec {
+    fn from(path: &str) -> Self {
+        Self::File(PathBuf::from(path))
+    }
+}
+
+/// Why generation failed.
+#[derive(Debug)]
+pub enum GeneratorError {
+    /// [`GeneratorBuilder::build`] was called without a spec.
+    MissingSpec,
+    /// The spec file could not be read or parsed.
+    Spec {
+        path: PathBuf,
+        source: Box<dyn Error + Send + Sync>,
+    },
+    /// A template failed to render.
+    Render {
+        template: &'static str,
+        source: askama::Error,
+    },
+    /// A template rendered code that does not parse.
+    InvalidRust(Box<InvalidRustError>),
+}
+
+impl fmt::Display for GeneratorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingSpec => write!(f, "no spec given to the generator"),
+            Self::Spec { path, source } => {
+                write!(f, "cannot read spec {}: {}", path.display(), source)
+            }
+            Self::Render { template, source } => {
+                write!(f, "cannot render template `{}`: {}", template, source)
+            }
+            Self::InvalidRust(e) => write!(f, "generated invalid Rust: {}", e),
+        }
+    }
+}
+
+impl GeneratorError {
+    /// JSON pointer to the spec item the error is about, when known.
+    pub fn pointer(&self) -> Option<String> {
+        match self {
+            Self::Spec { source, .. } => source.downcast_ref::<SpecError>()?.pointer.clone(),
+            Self::InvalidRust(e) => e.origin.as_ref().map(Origin::pointer),
+            Self::MissingSpec | Self::Render { .. } => None,
+        }
+    }
+}
+
+impl Error for GeneratorError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::MissingSpec => None,
+            Self::Spec { source, .. } => Some(source.as_ref()),
+            Self::Render { source, .. } => Some(source),
+            Self::InvalidRust(e) => Some(e.as_ref()),
+        }
+    }
+}
+
+impl From<Box<InvalidRustError>> for GeneratorError {
+    fn from(e: Box<InvalidRustError>) -> Self {
+        Self::InvalidRust(e)
+    }
+}
+
+/// What a generated file holds.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FileKind {
+    /// `src/{module}/handlers.rs`: the `Handlers` trait, handlers and router
+    /// of the operations under one path segment.
+    Handlers { module: String },
+    /// `src/models.rs`
+    Models,
+    /// `src/paths.rs`: a `TypedPath` per operation path.
+    Paths,
+    /// `src/errors.rs`
+    Errors,
+    /// `src/openapi.rs`
+    OpenApi,
+    /// `src/security.rs`
+    Security,
+    /// `src/docs.rs`
+    Docs,
+    /// `src/mod.rs`, declaring the other modules.
+    Module,
+    /// `src/client.rs`
+    Client,
+}
+
+/// How a file was produced.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Provenance {
+    /// The template that rendered the file.
+    pub template: &'static str,
+
+    /// The operations and schemas rendered into the file.
+    pub origins: Vec<Origin>,
+}
+
+/// A generated file, relative to the output directory.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GeneratedFile {
+    pub path: String,
+    pub kind: FileKind,
+    pub content: String,
+    pub provenance: Provenance,
+}
+
+impl GeneratedFile {
+    /// Renders `template` into an unformatted file without origins.
+    pub(crate) fn render(
+        path: impl Into<String>,
+        kind: FileKind,
+        template_name: &'static str,
+        template: &impl Template,
+    ) -> Result<Self, GeneratorError> {
+        let content = template.render().map_err(|source| GeneratorError::Render {
+            template: template_name,
+            source,
+        })?;
+        Ok(Self {
+            path: path.into(),
+            kind,
+            content,
+            provenance: Provenance {
+                template: template_name,
+                origins: Vec::new(),
+            },
+        })
+    }
+}
+
+/// Everything one run generates.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GeneratedProject {
+    pub files: Vec<GeneratedFile>,
+
+    /// What in the spec generation had to skip or work around.
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+impl GeneratedProject {
+    /// The file at `path`, relative to the output directory.
+    pub fn file(&self, path: &str) -> Option<&GeneratedFile> {
+        self.files.iter().find(|file| file.path == path)
+    }
+
+    /// The files as `(path, content)` pairs.
+    pub fn into_pairs(self) -> Vec<(String, String)> {
+        self.files
+            .into_iter()
+            .map(|file| (file.path, file.content))
+            .collect()
+    }
+
+    /// Writes the files under `output_dir`; see [`writer::write_files`].
+    pub fn write(&self, output_dir: &Path, remove_stale: bool) -> io::Result<WriteReport> {
+        writer::write_files(output_dir, &self.pairs(), remove_stale)
+    }
+
+    /// Lists what [`Self::write`] would change; see [`check::check_files`].
+    pub fn check(&self, output_dir: &Path, remove_stale: bool) -> io::Result<Vec<FileChange>> {
+        check::check_files(output_dir, &self.pairs(), remove_stale)
+    }
+
+    fn pairs(&self) -> Vec<(String, String)> {
+        self.clone().into_pairs()
+    }
+}
+
+/// Builds a [`Generator`].
+#[derive(Debug, Default)]
+pub struct GeneratorBuilder {
+    spec: Option<Spec>,
+    config: GeneratorConfig,
+    target: Option<Target>,
+}
+
+impl GeneratorBuilder {
+    /// The spec to generate from, as a path or a parsed [`OpenAPI`].
+    pub fn spec(mut self, spec: impl Into<Spec>) -> Self {
+        self.spec = Some(spec.into());
+        self
+    }
+
+    pub fn config(mut self, config: GeneratorConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Takes precedence over the config's target.
+    pub fn target(mut self, target: Target) -> Self {
+        self.target = Some(target);
+        self
+    }
+
+    /// Reads the spec.
+    pub fn build(self) -> Result<Generator, GeneratorError> {
+        let openapi = match self.spec.ok_or(GeneratorError::MissingSpec)? {
+            Spec::File(path) => {
+                let parsed = fs::read_to_string(&path)
+                    .map_err(Box::<dyn Error + Send + Sync>::from)
+                    .and_then(|content| Ok(openapi_from_str(&content)?));
+                match parsed {
+                    Ok(openapi) => openapi,
+                    Err(source) => return Err(GeneratorError::Spec { path, source }),
+                }
+            }
+            Spec::Document(openapi) => *openapi,
+        };
+        let mut config = self.config;
+        if let Some(target) = self.target {
+            config.target = target;
+        }
+        Ok(Generator { openapi, config })
+    }
+}
+
+/// Generates an Axum server or a client from an OpenAPI spec.
+#[derive(Debug, Clone)]
+pub struct Generator {
+    openapi: OpenAPI,
+    config: GeneratorConfig,
+}
+
+impl Generator {
+    pub fn builder() -> GeneratorBuilder {
+        GeneratorBuilder::default()
+    }
+
+    pub fn openapi(&self) -> &OpenAPI {
+        &self.openapi
+    }
+
+    pub fn config(&self) -> &GeneratorConfig {
+        &self.config
+    }
+
+    /// Renders every file, each Rust file parsed and formatted.
+    pub fn generate(&self) -> Result<GeneratedProject, GeneratorError> {
+        AxumTemplate::generate_project(&self.openapi, &self.config)
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/generator_test.rs"]
+mod generator_tests;
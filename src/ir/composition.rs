@@ -0,0 +1,162 @@
+//! Schema composition: `allOf` inheritance and `discriminator` polymorphism.
+
+use std::collections::HashSet;
+
+use openapiv3::{AdditionalProperties, Components, ReferenceOr, Schema, SchemaKind, Type};
+
+const SCHEMAS: &str = "#/components/schemas/";
+
+/// The properties of an object schema, including those it inherits through
+/// `allOf`. Later members override earlier ones.
+#[derive(Default)]
+pub struct ObjectView<'a> {
+    pub properties: Vec<(&'a str, &'a ReferenceOr<Box<Schema>>)>,
+    pub required: HashSet<&'a str>,
+    pub additional: Option<&'a AdditionalProperties>,
+}
+
+/// The object view of `schema`, or `None` for anything but an object or an
+/// `allOf` of objects.
+pub fn object_view<'a>(components: &'a Components, schema: &'a Schema) -> Option<ObjectView<'a>> {
+    let mut view = ObjectView::default();
+    collect(components, schema, &mut view, &mut HashSet::new())?;
+    Some(view)
+}
+
+fn collect<'a>(
+    components: &'a Components,
+    schema: &'a Schema,
+    view: &mut ObjectView<'a>,
+    seen: &mut HashSet<String>,
+) -> Option<()> {
+    match &schema.schema_kind {
+        SchemaKind::Type(Type::Object(object)) => {
+            for (name, property) in &object.properties {
+                view.properties.retain(|(existing, _)| existing != name);
+                view.properties.push((name, property));
+            }
+            view.required
+                .extend(object.required.iter().map(String::as_str));
+            if object.additional_properties.is_some() {
+                view.additional = object.additional_properties.as_ref();
+            }
+        }
+        SchemaKind::AllOf { all_of } => {
+            for member in all_of {
+                let member = match member {
+                    ReferenceOr::Item(member) => member,
+                    ReferenceOr::Reference { reference } => {
+                        let name = component_name(components, reference)?;
+                        // A cyclic hierarchy contributes each ancestor once
+                        if !seen.insert(name.clone()) {
+                            continue;
+                        }
+                        match components.schemas.get(&name)? {
+                            ReferenceOr::Item(member) => member,
+                            ReferenceOr::Reference { .. } => return None,
+                        }
+                    }
+                };
+                collect(components, member, view, seen)?;
+            }
+        }
+        _ => return None,
+    }
+    Some(())
+}
+
+/// An enum of component schemas told apart by a tag property.
+#[derive(Debug, Clone)]
+pub struct Polymorphism {
+    /// The `discriminator.propertyName` (e.g. "petType").
+    pub property: String,
+
+    /// The tag value and the component of each variant (e.g. ("cat", "Cat")).
+    pub variants: Vec<(String, String)>,
+}
+
+/// The variants of `name` when it has a `discriminator`: its `mapping`, or
+/// else its `oneOf`/`anyOf` members or, for a base schema, the components
+/// extending it through `allOf`, each tagged with its component name.
+pub fn polymorphism(components: &Components, name: &str, schema: &Schema) -> Option<Polymorphism> {
+    let discriminator = schema.schema_data.discriminator.as_ref()?;
+    let variants: Vec<(String, String)> = if !discriminator.mapping.is_empty() {
+        discriminator
+            .mapping
+            .iter()
+            .filter_map(|(tag, target)| {
+                // Mapping values are references or bare schema names
+                let target = match target.strip_prefix(SCHEMAS) {
+                    Some(_) => component_name(components, target)?,
+                    None => target.clone(),
+                };
+                Some((tag.clone(), target))
+            })
+            .collect()
+    } else {
+        let targets = match &schema.schema_kind {
+            SchemaKind::OneOf { one_of: members } | SchemaKind::AnyOf { any_of: members } => {
+                members
+                    .iter()
+                    .filter_map(|member| reference_name(components, member))
+                    .collect()
+            }
+            _ => subtypes(components, name),
+        };
+        targets
+            .into_iter()
+            .map(|target| (target.clone(), target))
+            .collect()
+    };
+
+    let variants: Vec<(String, String)> = variants
+        .into_iter()
+        .filter(|(_, target)| target != name && components.schemas.contains_key(target))
+        .collect();
+    if variants.is_empty() {
+        return None;
+    }
+    Some(Polymorphism {
+        property: discriminator.property_name.clone(),
+        variants,
+    })
+}
+
+/// The components whose `allOf` includes `name`.
+fn subtypes(components: &Components, name: &str) -> Vec<String> {
+    components
+        .schemas
+        .iter()
+        .filter(|(_, schema)| match schema {
+            ReferenceOr::Item(Schema {
+                schema_kind: SchemaKind::AllOf { all_of },
+                ..
+            }) => all_of
+                .iter()
+                .any(|member| reference_name(components, member).as_deref() == Some(name)),
+            _ => false,
+        })
+        .map(|(subtype, _)| subtype.clone())
+        .collect()
+}
+
+fn reference_name(components: &Components, schema: &ReferenceOr<Schema>) -> Option<String> {
+    match schema {
+        ReferenceOr::Reference { reference } => component_name(components, reference),
+        ReferenceOr::Item(_) => None,
+    }
+}
+
+/// The component a reference names, at the end of its chain of aliases, or
+/// `None` for non-local, cyclic and dangling references.
+pub fn component_name(components: &Components, reference: &str) -> Option<String> {
+    let mut name = reference.strip_prefix(SCHEMAS)?.to_string();
+    let mut seen = HashSet::new();
+    while let ReferenceOr::Reference { reference } = components.schemas.get(&name)? {
+        if !seen.insert(name.clone()) {
+            return None;
+        }
+        name = reference.strip_prefix(SCHEMAS)?.to_string();
+    }
+    Some(name)
+}
@@ -0,0 +1,58 @@
+use heck::ToSnakeCase;
+
+/// List of Rust keywords that need to be escaped
+const RUST_KEYWORDS: &[&str] = &[
+    "as", "break", "const", "continue", "crate", "else", "enum", "extern", "false", "fn", "for",
+    "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub", "ref", "return",
+    "self", "Self", "static", "struct", "super", "trait", "true", "type", "unsafe", "use", "where",
+    "while", "async", "await", "dyn", "abstract", "become", "box", "do", "final", "macro",
+    "override", "priv", "try", "typeof", "unsized", "virtual", "yield",
+];
+
+/// Keywords that cannot be raw identifiers.
+const PATH_KEYWORDS: &[&str] = &["crate", "self", "Self", "super"];
+
+/// Escape Rust keywords by prefixing with r#, or suffixing with `_` for the
+/// path keywords raw identifiers cannot spell
+pub fn escape_rust_keyword(name: &str) -> String {
+    if PATH_KEYWORDS.contains(&name) {
+        format!("{}_", name)
+    } else if RUST_KEYWORDS.contains(&name) {
+        format!("r#{}", name)
+    } else {
+        name.to_string()
+    }
+}
+
+/// The snake_case identifier of a spec name (e.g. "x-request-id" ->
+/// "x_request_id", "1st" -> "_1st", "type" -> "r#type").
+pub fn field_name(name: &str) -> String {
+    let snake: String = name
+        .to_snake_case()
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { '_' })
+        .collect();
+    escape_rust_keyword(&identifier(snake, "field"))
+}
+
+/// Makes `name`, already free of illegal characters, a valid identifier:
+/// `fallback` when it has no letters or digits, `_`-prefixed when it starts
+/// with a digit.
+pub(crate) fn identifier(name: String, fallback: &str) -> String {
+    if name.chars().all(|c| c == '_') {
+        fallback.to_string()
+    } else if name.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("_{}", name)
+    } else {
+        name
+    }
+}
+
+/// The name serde sees for an identifier: raw identifiers lose their `r#`.
+pub fn unraw(ident: &str) -> &str {
+    ident.strip_prefix("r#").unwrap_or(ident)
+}
+
+#[cfg(test)]
+#[path = "../tests/keywords_test.rs"]
+mod keywords_tests;
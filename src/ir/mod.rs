@@ -0,0 +1,119 @@
+//! The intermediate representation every template and backend renders from.
+//!
+//! A spec is translated once into operations and models. Types are mapped
+//! in [`types`] alone, so handlers, the client, the API doc and the models
+//! always agree on them.
+
+use std::fmt;
+
+use log::warn;
+use openapiv3::OpenAPI;
+use serde::Serialize;
+
+use crate::config::GeneratorConfig;
+use crate::security_translator::{SecuritySchemeSignature, SecurityTranslator};
+use resolve::Resolver;
+use types::TypeMapper;
+
+pub mod composition;
+pub mod keywords;
+pub mod models;
+pub mod operations;
+pub mod paths;
+pub mod resolve;
+pub mod types;
+
+pub use models::{Discriminator, Field, Model, Variant};
+pub use operations::{
+    axum_route, module_name, path_variables, Operation, Parameter, ParameterLocation, RequestBody,
+    Response, JSON_MEDIA_TYPES,
+};
+pub use paths::{PathField, TypedPath};
+
+/// Something in the spec that generation worked around.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Diagnostic {
+    /// JSON pointer to the spec item (e.g. "/paths/~1pets~1{petId}/get").
+    pub pointer: String,
+    pub message: String,
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "at #{}: {}", self.pointer, self.message)
+    }
+}
+
+/// A translated spec.
+#[derive(Debug, Clone, Serialize)]
+pub struct Api {
+    pub operations: Vec<Operation>,
+    pub models: Vec<Model>,
+    pub paths: Vec<TypedPath>,
+    pub security_schemes: Vec<SecuritySchemeSignature>,
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+impl Api {
+    /// Translates the spec with the default options.
+    pub fn from_openapi(openapi: &OpenAPI) -> Self {
+        Self::from_openapi_with_config(openapi, &GeneratorConfig::default())
+    }
+
+    /// Translates the spec, logging a warning per diagnostic.
+    pub fn from_openapi_with_config(openapi: &OpenAPI, config: &GeneratorConfig) -> Self {
+        // One resolver for the run: it holds the whole document
+        let resolver = Resolver::new(openapi);
+        let types = TypeMapper::for_spec(openapi, config);
+        let (mut operations, diagnostics) =
+            operations::translate_with_diagnostics(openapi, &types, &resolver);
+        for diagnostic in &diagnostics {
+            warn!("{}", diagnostic);
+        }
+
+        // Request bodies take the variant without the `readOnly` fields
+        let mut models = models::translate(openapi, &types);
+        let variants = models::split_directions(&mut models);
+        for operation in &mut operations {
+            if let Some(body) = &mut operation.request_body {
+                body.rust_type = types::rename_types(&body.rust_type, &variants);
+            }
+        }
+
+        Self {
+            paths: paths::translate(&operations),
+            operations,
+            models,
+            security_schemes: SecurityTranslator::new().translate(openapi, &resolver),
+            diagnostics,
+        }
+    }
+
+    /// The operations whose handlers live in `module`.
+    pub fn module_operations(&self, module: &str) -> Vec<Operation> {
+        self.operations
+            .iter()
+            .filter(|operation| operation.module == module)
+            .cloned()
+            .collect()
+    }
+
+    /// The handler modules, in order of first appearance.
+    pub fn modules(&self) -> Vec<String> {
+        let mut modules: Vec<String> = Vec::new();
+        for operation in &self.operations {
+            if !modules.contains(&operation.module) {
+                modules.push(operation.module.clone());
+            }
+        }
+        modules
+    }
+}
+
+#[cfg(test)]
+#[path = "../tests/ir_test.rs"]
+mod ir_tests;
+
+#[cfg(test)]
+#[path = "../tests/ir_petstore_test.rs"]
+mod ir_petstore_tests;
@@ -0,0 +1,536 @@
+use std::collections::{BTreeMap, HashSet};
+
+use openapiv3::{AdditionalProperties, Components, OpenAPI, ReferenceOr, Schema};
+use serde::Serialize;
+use serde_json::Value;
+
+use super::composition::{component_name, object_view, polymorphism, Polymorphism};
+use super::keywords::{field_name, unraw};
+use super::types::{rename_types, type_name, TypeMapper, UNTYPED};
+
+/// A struct, or for a schema with a `discriminator` an enum, generated from
+/// a component schema.
+#[derive(Debug, Clone, Serialize)]
+pub struct Model {
+    /// The Rust type name (e.g. "PetList").
+    pub name: String,
+
+    /// The key under `components.schemas` (e.g. "pet-list").
+    pub spec_name: String,
+
+    /// The schema's `description`, rendered as rustdoc.
+    pub description: Option<String>,
+
+    /// The object's properties, including inherited ones; empty for
+    /// anything but an object or an `allOf` of objects.
+    pub fields: Vec<Field>,
+
+    /// The variants, when the model is an internally tagged enum.
+    pub discriminator: Option<Discriminator>,
+
+    /// Attributes on the struct (e.g. "serde(deny_unknown_fields)").
+    pub attributes: Vec<String>,
+}
+
+impl Model {
+    /// Whether any field needs a default value function.
+    pub fn has_defaults(&self) -> bool {
+        self.fields.iter().any(|field| field.default.is_some())
+    }
+}
+
+/// A property of a [`Model`].
+#[derive(Debug, Clone, Serialize)]
+pub struct Field {
+    /// The Rust field name, a raw identifier for keywords.
+    pub name: String,
+
+    /// The Rust type, wrapped in `Option` when not required and without a
+    /// default.
+    pub rust_type: String,
+
+    /// Whether the property is listed in `required`.
+    pub required: bool,
+
+    /// The property's `description`, rendered as rustdoc.
+    pub description: Option<String>,
+
+    /// Whether the property is `readOnly`: sent in responses only.
+    pub read_only: bool,
+
+    /// Whether the property is `writeOnly`: sent in requests only.
+    pub write_only: bool,
+
+    /// The value of a missing property, from its `default`.
+    pub default: Option<DefaultValue>,
+
+    /// Attributes on the field (e.g. "serde(flatten)").
+    pub attributes: Vec<String>,
+}
+
+/// The variants of a polymorphic model, rendered as an internally tagged
+/// (`#[serde(tag = "...")]`) enum.
+#[derive(Debug, Clone, Serialize)]
+pub struct Discriminator {
+    /// The tag property (e.g. "petType").
+    pub property: String,
+
+    pub variants: Vec<Variant>,
+}
+
+/// A variant of a polymorphic model.
+#[derive(Debug, Clone, Serialize)]
+pub struct Variant {
+    /// The Rust variant name (e.g. "Cat").
+    pub name: String,
+
+    /// The tag value selecting it (e.g. "cat").
+    pub tag: String,
+
+    /// The model it holds, boxed when that model contains the enum.
+    pub rust_type: String,
+}
+
+/// A function returning a field's default, named in `#[serde(default)]`.
+#[derive(Debug, Clone, Serialize)]
+pub struct DefaultValue {
+    /// The associated function of the model (e.g. "default_status"), so
+    /// each variant of a model gets its own.
+    pub function: String,
+
+    /// The Rust expression of the value (e.g. `"available".to_string()`).
+    pub expression: String,
+}
+
+/// Translates every inline component schema into a model. Properties that
+/// would make a model contain itself, directly or through other models, are
+/// boxed. `additionalProperties` are collected into a flattened map field.
+/// Schemas with a `discriminator` become enums of their variants, each
+/// holding a payload struct that drops the tag property serde reads for it
+/// (e.g. "PetCat"); the variant schemas themselves keep it.
+pub fn translate(openapi: &OpenAPI, types: &TypeMapper) -> Vec<Model> {
+    let Some(components) = &openapi.components else {
+        return Vec::new();
+    };
+    let polymorphic: BTreeMap<&str, Polymorphism> = components
+        .schemas
+        .iter()
+        .filter_map(|(name, schema)| {
+            let ReferenceOr::Item(schema) = schema else {
+                return None;
+            };
+            Some((name.as_str(), polymorphism(components, name, schema)?))
+        })
+        .collect();
+    let graph = embedding_graph(components, &polymorphic);
+    let mut taken: HashSet<String> = components
+        .schemas
+        .keys()
+        .map(|name| type_name(name))
+        .collect();
+
+    let mut models = Vec::new();
+    for (name, schema) in &components.schemas {
+        let ReferenceOr::Item(schema) = schema else {
+            continue;
+        };
+        let mut attributes = Vec::new();
+        if let Some(example) = &schema.schema_data.example {
+            attributes.push(format!("schema(example = json!({}))", example));
+        }
+
+        let Some(enumeration) = polymorphic.get(name.as_str()) else {
+            let fields = object_fields(
+                components,
+                types,
+                &graph,
+                name,
+                schema,
+                None,
+                &mut attributes,
+            );
+            models.push(Model {
+                // References resolve to the same name
+                name: type_name(name),
+                spec_name: name.clone(),
+                description: schema.schema_data.description.clone(),
+                fields,
+                discriminator: None,
+                attributes,
+            });
+            continue;
+        };
+
+        let mut variants = Vec::new();
+        let mut payloads = Vec::new();
+        for (tag, target) in &enumeration.variants {
+            let Some(ReferenceOr::Item(target_schema)) = components.schemas.get(target) else {
+                continue;
+            };
+            let variant_name = type_name(tag);
+            let payload_name = (1..)
+                .map(|i| match i {
+                    1 => format!("{}{}", type_name(name), variant_name),
+                    i => format!("{}{}{}", type_name(name), variant_name, i),
+                })
+                .find(|candidate| taken.insert(candidate.clone()))
+                .expect("some suffix is free");
+            let key = payload_key(name, target);
+            let mut payload_attributes = Vec::new();
+            let fields = object_fields(
+                components,
+                types,
+                &graph,
+                &key,
+                target_schema,
+                Some(&enumeration.property),
+                &mut payload_attributes,
+            );
+            variants.push(Variant {
+                name: variant_name,
+                tag: tag.clone(),
+                rust_type: if reaches(&graph, &key, name) {
+                    format!("Box<{}>", payload_name)
+                } else {
+                    payload_name.clone()
+                },
+            });
+            payloads.push(Model {
+                name: payload_name,
+                spec_name: target.clone(),
+                description: target_schema.schema_data.description.clone(),
+                fields,
+                discriminator: None,
+                attributes: payload_attributes,
+            });
+        }
+
+        models.push(Model {
+            name: type_name(name),
+            spec_name: name.clone(),
+            description: schema.schema_data.description.clone(),
+            fields: Vec::new(),
+            discriminator: Some(Discriminator {
+                property: enumeration.property.clone(),
+                variants,
+            }),
+            attributes,
+        });
+        models.extend(payloads);
+    }
+    models
+}
+
+/// The fields of an object schema, keyed `key` in the embedding graph,
+/// leaving out the `tag` property. Pushes `serde(deny_unknown_fields)` onto
+/// `attributes` for objects closed to additional properties.
+fn object_fields(
+    components: &Components,
+    types: &TypeMapper,
+    graph: &Graph,
+    key: &str,
+    schema: &Schema,
+    tag: Option<&str>,
+    attributes: &mut Vec<String>,
+) -> Vec<Field> {
+    let Some(view) = object_view(components, schema) else {
+        return Vec::new();
+    };
+    let mut fields: Vec<Field> = Vec::new();
+    for (property, property_schema) in view.properties {
+        if Some(property) == tag {
+            continue;
+        }
+        let taken: Vec<String> = fields.iter().map(|f| f.name.clone()).collect();
+        let mut rust_type = types.boxed_rust_type(property_schema);
+        if embedded(components, property_schema).is_some_and(|target| reaches(graph, &target, key))
+        {
+            rust_type = format!("Box<{}>", rust_type);
+        }
+        fields.push(translate_field(
+            property,
+            property_schema,
+            rust_type,
+            view.required.contains(property),
+            &taken,
+        ));
+    }
+
+    if let Some(rust_type) = types.map_rust_type(view.additional) {
+        let taken: Vec<String> = fields.iter().map(|f| f.name.clone()).collect();
+        fields.push(Field {
+            name: unique("extra".to_string(), &taken),
+            rust_type,
+            required: true,
+            description: None,
+            read_only: false,
+            write_only: false,
+            default: None,
+            attributes: vec!["serde(flatten)".to_string()],
+        });
+    } else if matches!(view.additional, Some(AdditionalProperties::Any(false))) {
+        attributes.push("serde(deny_unknown_fields)".to_string());
+    }
+    fields
+}
+
+/// Splits every model with `readOnly` or `writeOnly` fields in two: the
+/// model itself, without the `writeOnly` fields, for responses, and a
+/// request variant without the `readOnly` ones, right after it. Models
+/// holding a split model, directly or through other models, get a request
+/// variant too, holding the request variants. Returns the request variant
+/// of each split model (e.g. "Pet" -> "PetCreate").
+pub fn split_directions(models: &mut Vec<Model>) -> BTreeMap<String, String> {
+    let mut taken: HashSet<String> = models.iter().map(|model| model.name.clone()).collect();
+    let mut variants = BTreeMap::new();
+    let mut request_name = |name: &str, variants: &mut BTreeMap<String, String>| {
+        let request = (1..)
+            .map(|i| match i {
+                1 => format!("{}Create", name),
+                i => format!("{}Create{}", name, i),
+            })
+            .find(|request| !taken.contains(request))
+            .expect("some suffix is free");
+        taken.insert(request.clone());
+        variants.insert(name.to_string(), request);
+    };
+
+    for model in models.iter() {
+        if model.fields.iter().any(|f| f.read_only || f.write_only) {
+            request_name(&model.name, &mut variants);
+        }
+    }
+    // Until no other model holds a split one
+    loop {
+        let holders: Vec<String> = models
+            .iter()
+            .filter(|model| !variants.contains_key(&model.name))
+            .filter(|model| holds_any(model, &variants))
+            .map(|model| model.name.clone())
+            .collect();
+        if holders.is_empty() {
+            break;
+        }
+        for name in holders {
+            request_name(&name, &mut variants);
+        }
+    }
+
+    let mut split = Vec::with_capacity(models.len() + variants.len());
+    for mut model in models.drain(..) {
+        let Some(name) = variants.get(&model.name) else {
+            split.push(model);
+            continue;
+        };
+        let mut request = model.clone();
+        request.name = name.clone();
+        request.fields.retain(|field| !field.read_only);
+        for field in &mut request.fields {
+            field.rust_type = rename_types(&field.rust_type, &variants);
+        }
+        if let Some(discriminator) = &mut request.discriminator {
+            for variant in &mut discriminator.variants {
+                variant.rust_type = rename_types(&variant.rust_type, &variants);
+            }
+        }
+        model.fields.retain(|field| !field.write_only);
+
+        split.push(model);
+        split.push(request);
+    }
+    *models = split;
+    variants
+}
+
+/// Whether a field or variant of `model` names one of the `split` models.
+fn holds_any(model: &Model, split: &BTreeMap<String, String>) -> bool {
+    let variants = model.discriminator.iter().flat_map(|d| &d.variants);
+    model
+        .fields
+        .iter()
+        .map(|field| &field.rust_type)
+        .chain(variants.map(|variant| &variant.rust_type))
+        .any(|rust_type| rename_types(rust_type, split) != *rust_type)
+}
+
+/// Translates one property, typed `rust_type` before `Option`
+/// wrapping and named apart from the `taken` fields. Only inline schemas
+/// carry documentation: OpenAPI 3.0 ignores the siblings of a `$ref`.
+fn translate_field(
+    property: &str,
+    schema: &ReferenceOr<Box<Schema>>,
+    rust_type: String,
+    required: bool,
+    taken: &[String],
+) -> Field {
+    let name = unique(field_name(property), taken);
+    let data = match schema {
+        ReferenceOr::Item(schema) => Some(&schema.schema_data),
+        ReferenceOr::Reference { .. } => None,
+    };
+
+    let mut attributes = Vec::new();
+    // Keep the wire name where the identifier differs
+    if unraw(&name) != property {
+        attributes.push(format!("serde(rename = {:?})", property));
+    }
+
+    let default_json = data.and_then(|data| data.default.as_ref());
+    let default = default_json.and_then(|value| {
+        Some(DefaultValue {
+            function: format!("default_{}", unraw(&name)),
+            expression: default_expression(&rust_type, value)?,
+        })
+    });
+
+    let mut features = Vec::new();
+    if let Some(example) = data.and_then(|data| data.example.as_ref()) {
+        features.push(format!("example = json!({})", example));
+    }
+    if let Some(value) = default_json {
+        features.push(format!("default = json!({})", value));
+    }
+    let read_only = data.is_some_and(|data| data.read_only);
+    let write_only = data.is_some_and(|data| data.write_only);
+    if read_only {
+        features.push("read_only".to_string());
+    }
+    if write_only {
+        features.push("write_only".to_string());
+    }
+    if !features.is_empty() {
+        attributes.push(format!("schema({})", features.join(", ")));
+    }
+    if data.is_some_and(|data| data.deprecated) {
+        attributes.push("deprecated".to_string());
+    }
+
+    Field {
+        name,
+        // A missing property with a default takes it instead of `None`
+        rust_type: if required || default.is_some() {
+            rust_type
+        } else {
+            format!("Option<{}>", rust_type)
+        },
+        required,
+        description: data.and_then(|data| data.description.clone()),
+        read_only,
+        write_only,
+        default,
+        attributes,
+    }
+}
+
+/// The Rust expression of a `default` for a field of `rust_type`, or `None`
+/// when the value does not fit the type or is not expressible as a literal.
+fn default_expression(rust_type: &str, value: &Value) -> Option<String> {
+    match (rust_type, value) {
+        ("String", Value::String(text)) => Some(format!("{:?}.to_string()", text)),
+        ("bool", Value::Bool(flag)) => Some(flag.to_string()),
+        ("i32" | "i64", Value::Number(number)) if number.is_i64() => {
+            Some(format!("{}{}", number, rust_type))
+        }
+        ("f32" | "f64", Value::Number(number)) => {
+            Some(format!("{}{}", number.as_f64()?, rust_type))
+        }
+        (UNTYPED, value) => Some(format!("serde_json::json!({})", value)),
+        (collection, Value::Array(_) | Value::Object(_))
+            if collection.starts_with("Vec<") || collection.starts_with("std::collections::") =>
+        {
+            Some(format!(
+                "serde_json::from_value(serde_json::json!({})).unwrap_or_default()",
+                value
+            ))
+        }
+        _ => None,
+    }
+}
+
+/// `name`, suffixed with a number when another field already has it (e.g.
+/// both "fooBar" and "foo_bar" map to "foo_bar").
+fn unique(name: String, taken: &[String]) -> String {
+    if !taken.contains(&name) {
+        return name;
+    }
+    (2..)
+        .map(|i| format!("{}_{}", name, i))
+        .find(|candidate| !taken.contains(candidate))
+        .expect("some suffix is free")
+}
+
+/// Which component schemas each one holds by value, keyed by spec name.
+/// Array items and untyped values sit behind a pointer already. An enum
+/// holds its variant payloads, keyed by [`payload_key`], which hold what
+/// their variant schema does.
+type Graph = BTreeMap<String, Vec<String>>;
+
+fn embedding_graph(components: &Components, polymorphic: &BTreeMap<&str, Polymorphism>) -> Graph {
+    let object_embeds = |schema: &Schema| -> Vec<String> {
+        object_view(components, schema)
+            .map(|view| {
+                view.properties
+                    .into_iter()
+                    .filter_map(|(_, property)| embedded(components, property))
+                    .collect()
+            })
+            .unwrap_or_default()
+    };
+    let mut graph = Graph::new();
+    for (name, schema) in &components.schemas {
+        let ReferenceOr::Item(schema) = schema else {
+            graph.insert(name.clone(), Vec::new());
+            continue;
+        };
+        let Some(enumeration) = polymorphic.get(name.as_str()) else {
+            graph.insert(name.clone(), object_embeds(schema));
+            continue;
+        };
+        let mut payloads = Vec::new();
+        for (_, target) in &enumeration.variants {
+            let key = payload_key(name, target);
+            let embeds = match components.schemas.get(target) {
+                Some(ReferenceOr::Item(target)) => object_embeds(target),
+                _ => Vec::new(),
+            };
+            graph.insert(key.clone(), embeds);
+            payloads.push(key);
+        }
+        graph.insert(name.clone(), payloads);
+    }
+    graph
+}
+
+/// The graph node of the payload `enumeration` holds for `target`, apart
+/// from `target` itself.
+fn payload_key(enumeration: &str, target: &str) -> String {
+    format!("{}#{}", enumeration, target)
+}
+
+/// The component a property embeds by value: the end of its `$ref` chain,
+/// or `None` for inline schemas and cyclic or dangling chains.
+fn embedded(components: &Components, property: &ReferenceOr<Box<Schema>>) -> Option<String> {
+    match property {
+        ReferenceOr::Reference { reference } => component_name(components, reference),
+        ReferenceOr::Item(_) => None,
+    }
+}
+
+/// Whether `from` embeds `to`, directly or through other components.
+fn reaches(graph: &Graph, from: &str, to: &str) -> bool {
+    let mut seen = HashSet::new();
+    let mut pending = vec![from];
+    while let Some(name) = pending.pop() {
+        if name == to {
+            return true;
+        }
+        if seen.insert(name) {
+            pending.extend(graph.get(name).into_iter().flatten().map(String::as_str));
+        }
+    }
+    false
+}
+
+#[cfg(test)]
+#[path = "../tests/models_test.rs"]
+mod models_tests;
@@ -0,0 +1,575 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+use heck::ToSnakeCase;
+use openapiv3::{OpenAPI, ParameterSchemaOrContent, ReferenceOr, Schema, StatusCode};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use super::keywords::{field_name, unraw};
+use super::resolve::Resolver;
+use super::types::TypeMapper;
+use super::Diagnostic;
+use crate::file_utils::json_pointer;
+use crate::security_translator::{operation_security, SecurityRequirementSignature};
+
+/// The methods an operation can be declared under, in the order operations
+/// of one path are translated.
+pub const HTTP_METHODS: &[&str] = &[
+    "GET", "POST", "PUT", "PATCH", "DELETE", "HEAD", "OPTIONS", "TRACE",
+];
+
+/// Media types whose bodies are translated, in order of preference.
+pub const JSON_MEDIA_TYPES: &[&str] = &["application/json", "application/problem+json"];
+
+/// An operation, rendered as a handler, a client method and an API doc path.
+#[derive(Debug, Clone, Serialize)]
+pub struct Operation {
+    /// The doc comments that will appear above the function definition.
+    /// Example:
+    /// "/// List all Todo items\n///\n/// List all Todo items from in-memory storage."
+    pub doc_comment: Option<String>,
+
+    /// The function name (e.g. "list_todos").
+    pub fn_name: String,
+
+    /// Whether the function is `async`.
+    pub is_async: bool,
+
+    /// The HTTP method (e.g. "GET", "POST", "PUT", etc.).
+    pub http_method: String,
+
+    /// The path/endpoint (e.g. "/todos").
+    pub path: String,
+
+    /// The axum route of the path (e.g. "/todos/:todo_id"); see [`axum_route`].
+    pub route: String,
+
+    /// The module the handler lives in (e.g. "todos"); see [`module_name`].
+    pub module: String,
+
+    /// An optional 'tag' or category for grouping endpoints (e.g. "Todo").
+    pub tag: String,
+
+    /// A short summary or description of what the endpoint does.
+    /// This can help populate doc comments or openapi "summary" fields.
+    pub summary: Option<String>,
+
+    /// The set of parameters expected by the function (path, query, etc.).
+    pub params: Vec<Parameter>,
+
+    /// The expected request body, if any (e.g. JSON of a certain type).
+    pub request_body: Option<RequestBody>,
+
+    /// The possible responses returned by this function.
+    /// In OpenAPI, there can be multiple response codes (200, 400, 404, etc.).
+    pub responses: Vec<Response>,
+
+    /// The Rust return type of the function (e.g. "Json<Vec<Todo>>").
+    pub return_type: Option<String>,
+
+    pub folder: String,
+
+    /// Alternative security requirements; satisfying any one grants access.
+    /// Empty when the operation is public (no global security or `security: []`).
+    pub security: Vec<SecurityRequirementSignature>,
+
+    /// Whether the operation is `deprecated`, rendered as `#[deprecated]`.
+    pub deprecated: bool,
+}
+
+/// Describes a parameter of an operation (path, query, etc.).
+#[derive(Debug, Clone, Serialize)]
+pub struct Parameter {
+    /// The parameter name on the wire (e.g. "todoId").
+    pub name: String,
+
+    /// The Rust identifier (e.g. "todo_id"), a raw identifier for keywords.
+    pub rust_name: String,
+
+    /// The Rust type of this parameter (e.g. "i32", "String", etc.).
+    pub rust_type: String,
+
+    /// Where the parameter is located (path, query, header, etc.).
+    pub location: ParameterLocation,
+
+    /// Description of what this parameter does, used in doc comments and OpenAPI.
+    pub description: Option<String>,
+
+    /// Whether the parameter must be supplied.
+    pub required: bool,
+}
+
+/// Indicates whether a parameter is found in a path, query, header, etc.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub enum ParameterLocation {
+    Path,
+    Query,
+    Header,
+    Cookie,
+}
+
+impl fmt::Display for ParameterLocation {
+    /// The utoipa `ParameterIn` variant (e.g. "Path").
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RequestBody {
+    /// The Rust type that represents the request body (e.g. "CreateTodo").
+    pub rust_type: String,
+
+    /// A brief description of the body for doc comments/OpenAPI.
+    pub description: Option<String>,
+}
+
+/// Describes one possible response of an operation (status code, body, etc.).
+#[derive(Debug, Clone, Serialize)]
+pub struct Response {
+    /// The HTTP status code (e.g. 200, 404, etc.); the first code of a range.
+    /// `None` for the `default` response, covering every status the others
+    /// do not.
+    pub status: Option<u16>,
+
+    /// The class of a range response (e.g. 4 for "4XX"), covering `status`
+    /// through `status + 99`.
+    pub range: Option<u16>,
+
+    /// Description of the response.
+    pub description: Option<String>,
+
+    /// The Rust type returned for this response (e.g. "[Todo]" or "ErrorMessage").
+    pub rust_type: Option<String>,
+
+    /// The declared media type (e.g. "application/json" or "application/problem+json").
+    pub content_type: Option<String>,
+}
+
+impl Response {
+    /// Whether the response reports an error: a 4XX or 5XX status, or
+    /// `default`.
+    pub fn is_error(&self) -> bool {
+        self.status.is_none_or(|status| status >= 400)
+    }
+}
+
+impl Default for Operation {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Operation {
+    /// Converts a string to snake_case format
+    /// Examples:
+    /// "HelloWorld" -> "hello_world"
+    /// "getUserInfo" -> "get_user_info"
+    /// "SomeHTTPRequest" -> "some_http_request"
+    /// "get_api_v4_network_validators_validatorsByClusterHash_clusterHash" -> "get_network_validators_validators_by_cluster_hash"
+    fn to_snake_case(input: &str) -> String {
+        let mut result = input.to_snake_case();
+
+        // Remove any "api_v4_" prefix
+        if result.contains("api_v4_") {
+            result = result.replace("api_v4_", "")
+        }
+
+        // Remove any "v4_" prefix
+        if result.contains("v4") {
+            result = result.replace("v4", "")
+        }
+
+        // Remove any "api"
+        // if result.contains("api") {
+        result = result.replace("api", "");
+        // }
+
+        // if there is a double underscore, remove one
+        if result.contains("__") {
+            result = result.replace("__", "_")
+        }
+
+        // Remove any underscores at the start or end of the string
+        result
+            .starts_with("_")
+            .then(|| result = result[1..].to_string());
+        result
+            .ends_with("_")
+            .then(|| result = result[..result.len() - 1].to_string());
+
+        //replace any "post" prefix with "create"
+        result
+            .starts_with("post_")
+            .then(|| result = result.replace("post_", "create_"));
+
+        // if starts with "search_" and ends with "_search" remove the "_search" part
+        if result.starts_with("search_") && result.ends_with("_search") {
+            result = result.replace("_search", "")
+        }
+
+        result = result.replace("get_get", "get");
+
+        result
+    }
+
+    /// JSON pointer to the operation in the spec.
+    pub fn pointer(&self) -> String {
+        json_pointer(["paths", &self.path, &self.http_method.to_lowercase()])
+    }
+
+    pub fn new() -> Self {
+        Self {
+            doc_comment: None,
+            fn_name: String::new(),
+            is_async: true,
+            http_method: String::new(),
+            path: String::new(),
+            route: String::new(),
+            module: String::new(),
+            tag: String::new(),
+            summary: None,
+            params: Vec::new(),
+            request_body: None,
+            responses: Vec::new(),
+            return_type: None,
+            folder: "default".to_string(),
+            security: Vec::new(),
+            deprecated: false,
+        }
+    }
+}
+
+/// Translates every operation of the spec. An empty spec gets a single
+/// default handler, so the generated server still has a route.
+pub fn translate(openapi: &OpenAPI) -> Vec<Operation> {
+    translate_with_diagnostics(openapi, &TypeMapper::default(), &Resolver::new(openapi)).0
+}
+
+/// [`translate`] with `types`, following references with `resolver`, also
+/// reporting what in the spec had to be skipped or looks wrong.
+pub fn translate_with_diagnostics(
+    openapi: &OpenAPI,
+    types: &TypeMapper,
+    resolver: &Resolver,
+) -> (Vec<Operation>, Vec<Diagnostic>) {
+    let mut operations = Vec::new();
+    let mut diagnostics = Vec::new();
+
+    // Schema references are typed rather than resolved, so a broken one is
+    // only noticed here; its type is left untyped
+    let mut broken = HashMap::new();
+    for (pointer, reference) in resolver.references("#/components/schemas/") {
+        let message = broken.entry(reference.clone()).or_insert_with(|| {
+            resolver
+                .resolve(&ReferenceOr::<Schema>::Reference { reference })
+                .err()
+        });
+        if let Some(message) = message {
+            diagnostics.push(Diagnostic {
+                pointer,
+                message: message.clone(),
+            });
+        }
+    }
+
+    if openapi.paths.paths.is_empty() {
+        let mut op = Operation::new();
+        op.fn_name = "default_handler".to_string();
+        op.path = "/".to_string();
+        op.route = axum_route("/");
+        op.module = module_name("/");
+        op.http_method = "GET".to_string();
+        op.is_async = true;
+        op.doc_comment = Some("/// Default handler for empty OpenAPI spec".to_string());
+        operations.push(op);
+        return (operations, diagnostics);
+    }
+
+    for (path, path_item) in openapi.paths.iter() {
+        let path_item = match resolver.resolve(path_item) {
+            Ok(path_item) => path_item,
+            Err(message) => {
+                diagnostics.push(Diagnostic {
+                    pointer: json_pointer(["paths", path]),
+                    message,
+                });
+                continue;
+            }
+        };
+
+        for method in HTTP_METHODS {
+            let Some((_, operation)) = path_item
+                .iter()
+                .find(|(name, _)| name.eq_ignore_ascii_case(method))
+            else {
+                continue;
+            };
+            let mut op = Operation::new();
+
+            op.path = path.clone();
+            op.route = axum_route(path);
+            op.module = module_name(path);
+            op.http_method = method.to_string();
+            op.fn_name = operation.operation_id.clone().unwrap_or_else(|| {
+                let path_name = path.replace('/', "_").trim_matches('_').to_string();
+                if path_name.is_empty() {
+                    method.to_lowercase()
+                } else {
+                    format!("{}_{}", method.to_lowercase(), path_name)
+                }
+            });
+
+            op.fn_name = Operation::to_snake_case(&op.fn_name);
+
+            // Set folder to first non-v4 tag if available, else default
+            op.folder = operation
+                .tags
+                .iter()
+                .find(|&tag| tag != "v4")
+                .map(|tag| tag.to_string())
+                .unwrap_or_else(|| "default".to_string());
+
+            // The summary leads, as rustdoc and utoipa both take the first
+            // paragraph as the title
+            let mut paragraphs: Vec<&str> = [&operation.summary, &operation.description]
+                .into_iter()
+                .flatten()
+                .map(|text| text.trim())
+                .filter(|text| !text.is_empty())
+                .collect();
+            paragraphs.dedup();
+            if !paragraphs.is_empty() {
+                op.doc_comment = Some(
+                    paragraphs
+                        .join("\n\n")
+                        .lines()
+                        .map(|line| format!("/// {}", line).trim_end().to_string())
+                        .collect::<Vec<_>>()
+                        .join("\n"),
+                );
+            }
+
+            op.deprecated = operation.deprecated;
+
+            op.is_async = true;
+
+            if !operation.tags.is_empty() {
+                op.tag = operation.tags.first().unwrap().clone();
+            }
+
+            op.summary = operation.summary.clone();
+
+            let (security, unmet) =
+                operation_security(openapi, resolver, operation.security.as_ref(), &op.pointer());
+            let skip = security.is_empty() && !unmet.is_empty();
+            for diagnostic in unmet {
+                // Global requirements are reported once, not per operation
+                if !diagnostics.contains(&diagnostic) {
+                    diagnostics.push(diagnostic);
+                }
+            }
+            if skip {
+                diagnostics.push(Diagnostic {
+                    pointer: op.pointer(),
+                    message: "no security requirement can be met, so the operation is skipped"
+                        .to_string(),
+                });
+                continue;
+            }
+            op.security = security;
+
+            // Path-level parameters apply to every operation, which may
+            // override them by name and location
+            let inherited = path_item
+                .parameters
+                .iter()
+                .enumerate()
+                .map(|(i, param)| (json_pointer(["paths", path, "parameters", &i.to_string()]), param));
+            let pointer = op.pointer();
+            let own = operation
+                .parameters
+                .iter()
+                .enumerate()
+                .map(|(i, param)| (format!("{}/parameters/{}", pointer, i), param));
+            for (pointer, param) in inherited.chain(own) {
+                let Some(param) = resolve(resolver, param, pointer, &mut diagnostics) else {
+                    continue;
+                };
+                let param = translate_parameter(&param, types);
+                match op
+                    .params
+                    .iter_mut()
+                    .find(|p| p.name == param.name && p.location == param.location)
+                {
+                    Some(existing) => *existing = param,
+                    None => op.params.push(param),
+                }
+            }
+
+            for variable in path_variables(path) {
+                let declared = op
+                    .params
+                    .iter()
+                    .any(|p| p.name == variable && p.location == ParameterLocation::Path);
+                if !declared {
+                    diagnostics.push(Diagnostic {
+                        pointer: op.pointer(),
+                        message: format!("path parameter `{{{}}}` is not declared", variable),
+                    });
+                }
+            }
+
+            let body = operation.request_body.as_ref().and_then(|body| {
+                let pointer = format!("{}/requestBody", pointer);
+                resolve(resolver, body, pointer, &mut diagnostics)
+            });
+            if let Some(body) = body {
+                if let Some(content) = body.content.get("application/json") {
+                    if let Some(schema) = &content.schema {
+                        op.request_body = Some(RequestBody {
+                            rust_type: types.rust_type(schema),
+                            description: body.description.clone(),
+                        });
+                    }
+                }
+            }
+
+            // `default` comes last, as it covers what the others do not
+            let responses = operation
+                .responses
+                .responses
+                .iter()
+                .map(|(status_code, response)| (Some(status_code), response))
+                .chain(operation.responses.default.iter().map(|response| (None, response)));
+            for (status_code, response) in responses {
+                let pointer = match status_code {
+                    Some(status_code) => format!("{}/responses/{}", pointer, status_code),
+                    None => format!("{}/responses/default", pointer),
+                };
+                let Some(response) = resolve(resolver, response, pointer, &mut diagnostics) else {
+                    continue;
+                };
+                let json_content = JSON_MEDIA_TYPES.iter().find_map(|media_type| {
+                    response
+                        .content
+                        .get(*media_type)
+                        .map(|content| (*media_type, content))
+                });
+                let (status, range) = match status_code {
+                    Some(StatusCode::Code(code)) => (Some(*code), None),
+                    Some(StatusCode::Range(range)) => (Some(range * 100), Some(*range)),
+                    None => (None, None),
+                };
+                // Bodies of other media types are not translated, but the
+                // response is still part of the contract (as are 201/204s)
+                let body = json_content.and_then(|(media_type, content)| {
+                    Some((types.rust_type(content.schema.as_ref()?), media_type.to_string()))
+                });
+                let (rust_type, content_type) = body.unzip();
+                op.responses.push(Response {
+                    status,
+                    range,
+                    description: Some(response.description.clone()),
+                    rust_type,
+                    content_type,
+                });
+            }
+
+            operations.push(op);
+        }
+    }
+
+    // A name that normalizes to nothing cannot be rendered
+    operations.retain(|op| !op.fn_name.is_empty());
+
+    // Handlers, client methods and API doc paths are all named after the
+    // operation, so repeated (or alike normalized) names are told apart
+    let mut taken = HashSet::new();
+    for op in &mut operations {
+        if taken.insert(op.fn_name.clone()) {
+            continue;
+        }
+        let name = (2..)
+            .map(|i| format!("{}_{}", op.fn_name, i))
+            .find(|candidate| !taken.contains(candidate))
+            .expect("some suffix is free");
+        diagnostics.push(Diagnostic {
+            pointer: op.pointer(),
+            message: format!(
+                "operation name `{}` is already taken, renamed to `{}`",
+                op.fn_name, name
+            ),
+        });
+        taken.insert(name.clone());
+        op.fn_name = name;
+    }
+    (operations, diagnostics)
+}
+
+/// The item behind `item`, or `None` with a diagnostic at `pointer`.
+fn resolve<T: DeserializeOwned + Clone>(
+    resolver: &Resolver,
+    item: &ReferenceOr<T>,
+    pointer: String,
+    diagnostics: &mut Vec<Diagnostic>,
+) -> Option<T> {
+    resolver
+        .resolve(item)
+        .map_err(|message| diagnostics.push(Diagnostic { pointer, message }))
+        .ok()
+}
+
+fn translate_parameter(param: &openapiv3::Parameter, types: &TypeMapper) -> Parameter {
+    let param_data = param.parameter_data_ref();
+    Parameter {
+        name: param_data.name.clone(),
+        rust_name: field_name(&param_data.name),
+        rust_type: match &param_data.format {
+            ParameterSchemaOrContent::Schema(schema) => types.rust_type(schema),
+            ParameterSchemaOrContent::Content(_) => "String".to_string(),
+        },
+        location: match param {
+            openapiv3::Parameter::Path { .. } => ParameterLocation::Path,
+            openapiv3::Parameter::Header { .. } => ParameterLocation::Header,
+            openapiv3::Parameter::Cookie { .. } => ParameterLocation::Cookie,
+            openapiv3::Parameter::Query { .. } => ParameterLocation::Query,
+        },
+        description: param_data.description.clone(),
+        required: param_data.required,
+    }
+}
+
+/// The `{name}` variables of a path template, in order.
+pub fn path_variables(path: &str) -> Vec<&str> {
+    path.split('{')
+        .skip(1)
+        .filter_map(|part| part.split_once('}').map(|(name, _)| name))
+        .collect()
+}
+
+/// A path template in axum's syntax, each `{name}` variable captured under
+/// its field name (e.g. "/pets/{petId}" -> "/pets/:pet_id").
+pub fn axum_route(path: &str) -> String {
+    let mut route = path.to_string();
+    for variable in path_variables(path) {
+        let capture = format!(":{}", unraw(&field_name(variable)));
+        route = route.replace(&format!("{{{}}}", variable), &capture);
+    }
+    route
+}
+
+/// The module an operation's handlers live in: its path's first literal
+/// segment, or `root` for `/` and paths starting with a parameter.
+pub fn module_name(path: &str) -> String {
+    path.split('/')
+        .find(|segment| !segment.is_empty())
+        .filter(|segment| !segment.starts_with('{'))
+        .map(field_name)
+        .unwrap_or_else(|| "root".to_string())
+}
+
+#[cfg(test)]
+#[path = "../tests/operations_test.rs"]
+mod operations_tests;
@@ -0,0 +1,86 @@
+use serde::Serialize;
+
+use super::keywords::field_name;
+use super::operations::{path_variables, Operation, ParameterLocation};
+use super::types::type_name;
+
+/// The types axum can both capture from and write into a path.
+const PATH_TYPES: &[&str] = &["String", "i32", "i64", "f32", "f64", "bool"];
+
+/// An `axum_extra` `TypedPath` struct for one path of the spec, usable as an
+/// extractor and to build URLs.
+#[derive(Debug, Clone, Serialize)]
+pub struct TypedPath {
+    /// The struct name (e.g. "PetsPetIdPath").
+    pub name: String,
+
+    /// The path as written in the spec (e.g. "/pets/{petId}").
+    pub path: String,
+
+    /// The axum route (e.g. "/pets/:pet_id").
+    pub route: String,
+
+    /// One field per path variable, in order.
+    pub fields: Vec<PathField>,
+}
+
+/// A captured variable of a [`TypedPath`].
+#[derive(Debug, Clone, Serialize)]
+pub struct PathField {
+    /// The field name, which is also the capture name (e.g. "pet_id").
+    pub name: String,
+
+    /// The Rust type, from the path parameter of the first operation
+    /// declaring it; `String` when undeclared or not a scalar.
+    pub rust_type: String,
+}
+
+/// One typed path per distinct operation path, in order of first appearance.
+pub fn translate(operations: &[Operation]) -> Vec<TypedPath> {
+    let mut paths: Vec<TypedPath> = Vec::new();
+    for operation in operations {
+        if paths.iter().any(|path| path.path == operation.path) {
+            continue;
+        }
+
+        let fields = path_variables(&operation.path)
+            .into_iter()
+            .map(|variable| {
+                let rust_type = operations
+                    .iter()
+                    .filter(|other| other.path == operation.path)
+                    .flat_map(|other| &other.params)
+                    .find(|p| p.name == variable && p.location == ParameterLocation::Path)
+                    .map(|p| p.rust_type.as_str())
+                    .filter(|rust_type| PATH_TYPES.contains(rust_type))
+                    .unwrap_or("String");
+                PathField {
+                    name: field_name(variable),
+                    rust_type: rust_type.to_string(),
+                }
+            })
+            .collect();
+
+        // "/pets/{petId}" -> "PetsPetIdPath"
+        let words = operation.path.trim_matches('/');
+        let words = if words.is_empty() { "root" } else { words };
+        let mut name = type_name(&format!("{}-path", words));
+        let mut suffix = 2;
+        while paths.iter().any(|path| path.name == name) {
+            name = type_name(&format!("{}-path-{}", words, suffix));
+            suffix += 1;
+        }
+
+        paths.push(TypedPath {
+            name,
+            path: operation.path.clone(),
+            route: operation.route.clone(),
+            fields,
+        });
+    }
+    paths
+}
+
+#[cfg(test)]
+#[path = "../tests/paths_test.rs"]
+mod paths_tests;
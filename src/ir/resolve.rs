@@ -0,0 +1,80 @@
+use std::collections::HashSet;
+
+use openapiv3::{OpenAPI, ReferenceOr};
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use crate::file_utils::json_pointer;
+
+/// Follows local `$ref`s (`#/...`) through the document.
+pub struct Resolver {
+    document: Value,
+}
+
+impl Resolver {
+    pub fn new(openapi: &OpenAPI) -> Self {
+        Self {
+            document: serde_json::to_value(openapi).unwrap_or(Value::Null),
+        }
+    }
+
+    /// The item `item` refers to, following chains of references. Fails on
+    /// external, missing, mistyped and cyclic references.
+    pub fn resolve<T: DeserializeOwned + Clone>(&self, item: &ReferenceOr<T>) -> Result<T, String> {
+        let mut seen = HashSet::new();
+        let mut current = item.clone();
+        loop {
+            let reference = match current {
+                ReferenceOr::Item(item) => return Ok(item),
+                ReferenceOr::Reference { reference } => reference,
+            };
+            let Some(pointer) = reference.strip_prefix('#') else {
+                return Err(format!("external reference `{}` is not supported", reference));
+            };
+            if !seen.insert(reference.clone()) {
+                return Err(format!("reference `{}` refers to itself", reference));
+            }
+            let target = self
+                .document
+                .pointer(pointer)
+                .ok_or_else(|| format!("reference `{}` does not resolve", reference))?;
+            current = serde_json::from_value(target.clone())
+                .map_err(|e| format!("reference `{}` is invalid: {}", reference, e))?;
+        }
+    }
+
+    /// The local `$ref`s starting with `prefix` (e.g.
+    /// "#/components/schemas/"), each with the JSON pointer to the object
+    /// holding it, in document order.
+    pub fn references(&self, prefix: &str) -> Vec<(String, String)> {
+        let mut references = Vec::new();
+        collect_references(&self.document, prefix, &mut Vec::new(), &mut references);
+        references
+    }
+}
+
+fn collect_references(
+    value: &Value,
+    prefix: &str,
+    segments: &mut Vec<String>,
+    references: &mut Vec<(String, String)>,
+) {
+    let children: Vec<(String, &Value)> = match value {
+        Value::Object(object) => {
+            if let Some(Value::String(reference)) = object.get("$ref") {
+                if reference.starts_with(prefix) {
+                    let pointer = json_pointer(segments.iter().map(String::as_str));
+                    references.push((pointer, reference.clone()));
+                }
+            }
+            object.iter().map(|(key, item)| (key.clone(), item)).collect()
+        }
+        Value::Array(items) => items.iter().enumerate().map(|(i, item)| (i.to_string(), item)).collect(),
+        _ => return,
+    };
+    for (segment, child) in children {
+        segments.push(segment);
+        collect_references(child, prefix, segments, references);
+        segments.pop();
+    }
+}
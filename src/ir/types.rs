@@ -0,0 +1,170 @@
+use std::collections::BTreeMap;
+
+use openapiv3::{
+    AdditionalProperties, IntegerFormat, NumberFormat, OpenAPI, ReferenceOr, Schema, SchemaKind,
+    Type, VariantOrUnknownOrEmpty,
+};
+
+use super::composition::component_name;
+use super::keywords::{escape_rust_keyword, identifier};
+use crate::config::{GeneratorConfig, MapType};
+
+/// The type of anything the generator cannot type more precisely.
+pub const UNTYPED: &str = "serde_json::Value";
+
+const SCHEMAS: &str = "#/components/schemas/";
+
+/// Maps schemas to Rust types, wherever they appear: parameters, bodies,
+/// responses and model fields all map through here.
+#[derive(Debug, Clone, Default)]
+pub struct TypeMapper {
+    /// The map emitted for `additionalProperties`.
+    pub map_type: MapType,
+
+    /// The model each component schema key maps to: the key itself, or
+    /// for an alias (a component that is only a `$ref`) the end of its
+    /// chain; `None` for cyclic and dangling chains. Without a spec, every
+    /// reference names its component.
+    pub components: Option<BTreeMap<String, Option<String>>>,
+}
+
+impl TypeMapper {
+    pub fn from_config(config: &GeneratorConfig) -> Self {
+        Self {
+            map_type: config.map_type,
+            components: None,
+        }
+    }
+
+    /// [`Self::from_config`], mapping references to the components of
+    /// `openapi`: an alias maps to the model it stands for, as it gets none
+    /// of its own, and a reference that does not resolve is left untyped.
+    pub fn for_spec(openapi: &OpenAPI, config: &GeneratorConfig) -> Self {
+        let components = openapi
+            .components
+            .iter()
+            .flat_map(|components| {
+                components.schemas.keys().map(|name| {
+                    let reference = format!("{}{}", SCHEMAS, name);
+                    (name.clone(), component_name(components, &reference))
+                })
+            })
+            .collect();
+        Self {
+            components: Some(components),
+            ..Self::from_config(config)
+        }
+    }
+
+    /// The Rust type of a schema.
+    pub fn rust_type(&self, schema: &ReferenceOr<Schema>) -> String {
+        match schema {
+            ReferenceOr::Item(schema) => self.schema_type(schema),
+            ReferenceOr::Reference { reference } => self.reference_type(reference),
+        }
+    }
+
+    /// [`Self::rust_type`] for the boxed schemas of properties and array items.
+    pub fn boxed_rust_type(&self, schema: &ReferenceOr<Box<Schema>>) -> String {
+        match schema {
+            ReferenceOr::Item(schema) => self.schema_type(schema),
+            ReferenceOr::Reference { reference } => self.reference_type(reference),
+        }
+    }
+
+    /// The Rust type a schema `$ref` resolves to. Models are emitted for
+    /// local component schemas only; anything else is left untyped.
+    pub fn reference_type(&self, reference: &str) -> String {
+        let Some(name) = reference.strip_prefix(SCHEMAS) else {
+            return UNTYPED.to_string();
+        };
+        match &self.components {
+            Some(components) => match components.get(name) {
+                Some(Some(target)) => type_name(target),
+                _ => UNTYPED.to_string(),
+            },
+            None => type_name(name),
+        }
+    }
+
+    /// The map holding an object's `additionalProperties`, or `None` when
+    /// they are absent or forbidden.
+    pub fn map_rust_type(&self, additional: Option<&AdditionalProperties>) -> Option<String> {
+        let value = match additional? {
+            AdditionalProperties::Any(true) => UNTYPED.to_string(),
+            AdditionalProperties::Any(false) => return None,
+            AdditionalProperties::Schema(schema) => self.rust_type(schema),
+        };
+        let map = match self.map_type {
+            MapType::HashMap => "std::collections::HashMap",
+            MapType::BTreeMap => "std::collections::BTreeMap",
+        };
+        Some(format!("{}<String, {}>", map, value))
+    }
+
+    fn schema_type(&self, schema: &Schema) -> String {
+        match &schema.schema_kind {
+            SchemaKind::Type(Type::String(_)) => "String".to_string(),
+            SchemaKind::Type(Type::Integer(integer)) => match integer.format {
+                VariantOrUnknownOrEmpty::Item(IntegerFormat::Int32) => "i32".to_string(),
+                _ => "i64".to_string(),
+            },
+            SchemaKind::Type(Type::Number(number)) => match number.format {
+                VariantOrUnknownOrEmpty::Item(NumberFormat::Float) => "f32".to_string(),
+                _ => "f64".to_string(),
+            },
+            SchemaKind::Type(Type::Boolean(_)) => "bool".to_string(),
+            SchemaKind::Type(Type::Array(array)) => format!(
+                "Vec<{}>",
+                array
+                    .items
+                    .as_ref()
+                    .map_or_else(|| UNTYPED.to_string(), |items| self.boxed_rust_type(items))
+            ),
+            // An inline object without properties is a plain map
+            SchemaKind::Type(Type::Object(object)) if object.properties.is_empty() => self
+                .map_rust_type(object.additional_properties.as_ref())
+                .unwrap_or_else(|| UNTYPED.to_string()),
+            // Other inline objects and compositions have no model to name
+            _ => UNTYPED.to_string(),
+        }
+    }
+}
+
+/// `rust_type` with every type name in `renames` replaced (e.g.
+/// "Vec<Pet>" -> "Vec<PetCreate>").
+pub fn rename_types(rust_type: &str, renames: &BTreeMap<String, String>) -> String {
+    let mut result = String::new();
+    let mut ident = String::new();
+    for c in rust_type.chars().chain(std::iter::once(' ')) {
+        if c.is_alphanumeric() || c == '_' || c == ':' {
+            ident.push(c);
+            continue;
+        }
+        result.push_str(renames.get(&ident).unwrap_or(&ident));
+        ident.clear();
+        result.push(c);
+    }
+    result.pop();
+    result
+}
+
+/// The Rust type name of a component schema (e.g. "pet-list" -> "PetList",
+/// "dataSetList" -> "DataSetList"). Illegal characters start a new word.
+pub fn type_name(name: &str) -> String {
+    let mut result = String::new();
+    let mut capitalize_next = true;
+
+    for c in name.chars() {
+        if !c.is_alphanumeric() {
+            capitalize_next = true;
+        } else if capitalize_next {
+            result.extend(c.to_uppercase());
+            capitalize_next = false;
+        } else {
+            result.push(c);
+        }
+    }
+
+    escape_rust_keyword(&identifier(result, "Model"))
+}
@@ -1,225 +1,585 @@
 use askama::Template;
+use heck::ToUpperCamelCase;
 use log::{debug, error, info};
 
 use openapiv3::OpenAPI;
-use serde::Serialize;
-
+use std::collections::BTreeSet;
+
+pub mod api_doc_translator;
+pub mod build;
+pub mod check;
+pub mod client_translator;
+pub mod config;
+pub mod docs_translator;
+pub mod errors_translator;
 pub mod file_utils;
 pub mod filters;
-pub mod functions_translator;
-pub mod routes;
-pub mod routes_translator;
+pub mod formatter;
+pub mod generator;
+pub mod ir;
 pub mod schema_generator;
-pub mod schemas_translator;
+pub mod security_translator;
 pub mod test_utils;
+pub mod user_code;
+pub mod validate;
+pub mod watch;
+pub mod writer;
 
+pub use generator::{FileKind, GeneratedFile, GeneratedProject, Generator, GeneratorError};
 pub use schema_generator::generate_types_from_schemas;
 
-#[cfg(test)]
-mod routes_translator_petstore_test;
-#[cfg(test)]
-mod routes_translator_test;
-#[cfg(test)]
-mod routes_translator_uspto_test;
-
-use functions_translator::FunctionSignature;
-use routes_translator::RoutesTranslator;
-use schemas_translator::SchemasTranslator;
+use api_doc_translator::{ApiDocSignature, ApiDocTranslator};
+use client_translator::{ClientOperation, ClientTranslator};
+use config::{DocsUi, ErrorStyle, GeneratorConfig, Target};
+use docs_translator::{DocsSignature, DocsTranslator};
+use errors_translator::{ErrorVariantSignature, ErrorsTranslator};
+use ir::{Api, Model, Operation, Parameter, ParameterLocation, Response, TypedPath};
+use security_translator::{SchemeKind, SecuritySchemeSignature};
+use validate::{Origin, OriginMarker};
 
 #[derive(Template)]
 #[template(path = "axum_utoipa.rs.jinja", escape = "none")]
 pub struct AxumTemplate<'a> {
     pub openapi: &'a OpenAPI,
-    pub routes: Vec<RouteWithoutTags>,
-    pub functions: Vec<FunctionSignature>,
-    pub folders: std::collections::HashSet<String>,
-    pub schemas: Vec<Schema>,
+    pub operations: Vec<Operation>,
+    pub models: Vec<Model>,
+    /// The typed paths the handlers extract their path parameters into.
+    pub paths: Vec<TypedPath>,
+    pub security_schemes: Vec<SecuritySchemeSignature>,
+    pub error_style: ErrorStyle,
+}
+
+/// A value a handler extracts from the request and passes on to its
+/// `Handlers` method.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HandlerArgument {
+    /// The parameter name (e.g. "query").
+    pub name: String,
+    /// The type the method receives (e.g. "ListPetsQuery").
+    pub rust_type: String,
+    /// The handler parameter, which keeps the rejection (e.g.
+    /// "query: Result<Query<ListPetsQuery>, QueryRejection>").
+    pub extractor: String,
+    /// The statement unwrapping the parameter into an `ApiError` on rejection
+    /// (e.g. "let Query(query) = query?;"); `None` for infallible extractors.
+    pub binding: Option<String>,
 }
 
 impl<'a> AxumTemplate<'a> {
     fn new(
         openapi: &'a OpenAPI,
-        routes: Vec<RouteWithoutTags>,
-        schemas: Vec<Schema>,
-        functions: Vec<FunctionSignature>,
+        models: Vec<Model>,
+        operations: Vec<Operation>,
+        security_schemes: Vec<SecuritySchemeSignature>,
     ) -> Self {
         Self {
             openapi,
-            routes,
-            schemas,
-            functions,
-            folders: std::collections::HashSet::new(),
+            models,
+            operations,
+            paths: Vec::new(),
+            security_schemes,
+            error_style: ErrorStyle::default(),
         }
     }
-}
 
-#[derive(Serialize)]
-pub struct RouteWithoutTags {
-    pub path: String,
-    pub method: String,
-    pub handler_name: String,
-    pub schema: SchemaRef,
-    pub parameters: Vec<Parameter>,
-    pub path_parameters: Vec<String>,
-    pub responses: Vec<Response>,
-    pub tag: String,
+    /// Whether the router guards any route with the security middleware.
+    fn secured(&self) -> bool {
+        self.operations.iter().any(|operation| !operation.security.is_empty())
+    }
+
+    /// Whether the router registers a `#[deprecated]` handler.
+    fn any_deprecated(&self) -> bool {
+        self.operations.iter().any(|operation| operation.deprecated)
+    }
+
+    /// The `axum::routing` functions the router calls, in import order.
+    fn routing_methods(&self) -> Vec<String> {
+        let methods: BTreeSet<_> = self
+            .operations
+            .iter()
+            .map(|operation| operation.http_method.to_lowercase())
+            .collect();
+        methods.into_iter().collect()
+    }
+
+    /// The responses of `operation` with their `status` in `#[utoipa::path]`
+    /// (e.g. `200`, `"4XX"`, `"default"`).
+    fn documented_responses(&self, operation: &Operation) -> Vec<(String, Response)> {
+        operation
+            .responses
+            .iter()
+            .map(|response| {
+                let status = match (response.status, response.range) {
+                    (_, Some(range)) => format!("\"{}XX\"", range),
+                    (Some(status), None) => status.to_string(),
+                    (None, None) => "\"default\"".to_string(),
+                };
+                (status, response.clone())
+            })
+            .collect()
+    }
+
+    /// Whether `#[utoipa::path]` can express the security of `operation`,
+    /// which needs one scheme per requirement. The API doc copies it from the
+    /// source spec either way.
+    fn attributes_security(&self, operation: &Operation) -> bool {
+        !operation.security.is_empty()
+            && operation
+                .security
+                .iter()
+                .all(|requirement| requirement.schemes.len() <= 1)
+    }
+
+    /// The query parameters of `operation`.
+    fn query_params<'o>(&self, operation: &'o Operation) -> Vec<&'o Parameter> {
+        operation
+            .params
+            .iter()
+            .filter(|param| param.location == ParameterLocation::Query)
+            .collect()
+    }
+
+    /// The struct the query parameters of `operation` deserialize into
+    /// (e.g. "ListPetsQuery").
+    fn query_type(&self, operation: &Operation) -> String {
+        format!("{}Query", operation.fn_name.to_upper_camel_case())
+    }
+
+    /// Whether any handler extracts the argument `name`.
+    fn extracts(&self, name: &str) -> bool {
+        self.operations
+            .iter()
+            .any(|operation| self.arguments(operation).iter().any(|arg| arg.name == name))
+    }
+
+    /// What the handler of `operation` extracts, in extraction order: the
+    /// typed path, the query, the headers (for header and cookie
+    /// parameters), then the JSON body, which consumes the request.
+    fn arguments(&self, operation: &Operation) -> Vec<HandlerArgument> {
+        let mut arguments = Vec::new();
+        let typed_path = self
+            .paths
+            .iter()
+            .find(|path| path.path == operation.path && !path.fields.is_empty());
+        if let Some(path) = typed_path {
+            let rust_type = format!("crate::paths::{}", path.name);
+            arguments.push(HandlerArgument {
+                name: "path".to_string(),
+                extractor: format!("path: Result<{}, PathRejection>", rust_type),
+                binding: Some("let path = path?;".to_string()),
+                rust_type,
+            });
+        }
+        if !self.query_params(operation).is_empty() {
+            let rust_type = self.query_type(operation);
+            arguments.push(HandlerArgument {
+                name: "query".to_string(),
+                extractor: format!("query: Result<Query<{}>, QueryRejection>", rust_type),
+                binding: Some("let Query(query) = query?;".to_string()),
+                rust_type,
+            });
+        }
+        let has_headers = operation.params.iter().any(|param| {
+            matches!(
+                param.location,
+                ParameterLocation::Header | ParameterLocation::Cookie
+            )
+        });
+        if has_headers {
+            arguments.push(HandlerArgument {
+                name: "headers".to_string(),
+                extractor: "headers: HeaderMap".to_string(),
+                binding: None,
+                rust_type: "HeaderMap".to_string(),
+            });
+        }
+        if let Some(body) = &operation.request_body {
+            arguments.push(HandlerArgument {
+                name: "body".to_string(),
+                extractor: format!("body: Result<Json<{}>, JsonRejection>", body.rust_type),
+                binding: Some("let Json(body) = body?;".to_string()),
+                rust_type: body.rust_type.clone(),
+            });
+        }
+        arguments
+    }
 }
 
 #[derive(Template)]
 #[template(path = "mod.rs.jinja", escape = "none")]
 pub struct ModTemplate {
+    /// The modules holding a `handlers` module, each with its own router.
+    pub handlers: Vec<String>,
     pub modules: Vec<String>,
+    /// Router expressions merged into `app()`; no `app()` when empty.
+    pub routers: Vec<String>,
+    /// Whether the module routers take an authenticator.
+    pub authenticated: bool,
 }
 
-#[derive(Serialize)]
-pub struct Route {
-    pub path: String,
-    pub method: String,
-    pub handler_name: String,
-    pub schema: SchemaRef,
-    pub parameters: Vec<Parameter>,
-    pub path_parameters: Vec<String>,
-    pub responses: Vec<Response>,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
-    pub tags: Vec<String>,
+#[derive(Template)]
+#[template(path = "security.rs.jinja", escape = "none")]
+pub struct SecurityTemplate {
+    pub schemes: Vec<SecuritySchemeSignature>,
+    pub operations: Vec<Operation>,
 }
 
-#[derive(Serialize)]
-pub struct SchemaRef {
-    pub name: String,
+#[derive(Template)]
+#[template(path = "models.rs.jinja", escape = "none")]
+pub struct ModelsTemplate {
+    pub models: Vec<Model>,
 }
 
-#[derive(Serialize)]
-pub struct Parameter {
-    pub name: String,
-    pub param_type: String,
-    pub required: bool,
+#[derive(Template)]
+#[template(path = "paths.rs.jinja", escape = "none")]
+pub struct PathsTemplate {
+    pub paths: Vec<TypedPath>,
+}
+
+#[derive(Template)]
+#[template(path = "errors.rs.jinja", escape = "none")]
+pub struct ErrorsTemplate {
+    pub errors: Vec<ErrorVariantSignature>,
+    pub problem: bool,
 }
 
-#[derive(Serialize)]
-pub struct Response {
-    pub status_code: String,
-    pub description: String,
-    pub content_type: String,
+impl ErrorsTemplate {
+    /// Whether an error response renders a JSON body.
+    fn renders_json(&self) -> bool {
+        self.problem || self.errors.iter().any(|error| error.body.is_some())
+    }
 }
 
-#[derive(Serialize, Clone)]
-pub struct Schema {
-    pub name: String,
-    pub path: String,
-    pub fields: Vec<SchemaField>,
+#[derive(Template)]
+#[template(path = "openapi.rs.jinja", escape = "none")]
+pub struct OpenApiTemplate {
+    pub doc: ApiDocSignature,
 }
 
-#[derive(Serialize, Clone)]
-pub struct SchemaField {
-    pub name: String,
-    pub field_type: String,
-    pub rust_type: String,
-    pub required: bool,
+#[derive(Template)]
+#[template(path = "docs.rs.jinja", escape = "none")]
+pub struct DocsTemplate {
+    pub docs: DocsSignature,
+}
+
+#[derive(Template)]
+#[template(path = "client.rs.jinja", escape = "none")]
+pub struct ClientTemplate {
+    pub operations: Vec<ClientOperation>,
+    pub base_url: Option<String>,
 }
 
 impl AxumTemplate<'_> {
-    pub fn from_openapi(openapi: &OpenAPI) -> Vec<(String, String)> {
-        info!("Starting OpenAPI translation");
-        let routes_translator = RoutesTranslator::new();
-        let schemas_translator = SchemasTranslator::new();
-        let functions_translator = FunctionSignature::new();
+    /// Renders every file with the default options.
+    ///
+    /// Kept for callers predating [`Generator`], which also reports each
+    /// file's kind and provenance.
+    pub fn from_openapi(openapi: &OpenAPI) -> Result<Vec<(String, String)>, GeneratorError> {
+        Self::from_openapi_with_config(openapi, &GeneratorConfig::default())
+    }
 
-        debug!("Initialized translators");
+    /// Renders every file for `config` as `(path, content)` pairs.
+    pub fn from_openapi_with_config(
+        openapi: &OpenAPI,
+        config: &GeneratorConfig,
+    ) -> Result<Vec<(String, String)>, GeneratorError> {
+        Ok(Self::generate_project(openapi, config)?.into_pairs())
+    }
 
-        let routes = routes_translator.translate(openapi);
-        debug!("Translated {} routes", routes.len());
-        
-        let schemas = schemas_translator.translate(openapi);
-        debug!("Translated {} schemas", schemas.len());
+    /// Renders every file for `config`, each Rust file parsed and formatted.
+    /// Fails on the first file that is not valid Rust instead of returning it.
+    pub(crate) fn generate_project(
+        openapi: &OpenAPI,
+        config: &GeneratorConfig,
+    ) -> Result<GeneratedProject, GeneratorError> {
+        let api = Api::from_openapi_with_config(openapi, config);
+        let markers = Self::origin_markers(&api);
+
+        let files = Self::render_files(openapi, &api, config)?
+            .into_iter()
+            .map(|mut file| {
+                if file.path.ends_with(".rs") {
+                    file.content = validate::validate_and_format(
+                        &file.path,
+                        file.provenance.template,
+                        &file.content,
+                        &markers,
+                    )
+                    .inspect_err(|e| error!("Generated invalid Rust: {}", e))?;
+                }
+                file.provenance.origins = Self::file_origins(&file.kind, &api);
+                Ok(file)
+            })
+            .collect::<Result<_, GeneratorError>>()?;
+        Ok(GeneratedProject {
+            files,
+            diagnostics: api.diagnostics,
+        })
+    }
 
-        let mut modules = Vec::new();
-        let mut module_routes = std::collections::HashMap::new();
-        debug!("Starting module organization");
-        
+    fn operation_origin(operation: &Operation) -> Origin {
+        Origin::Operation {
+            name: operation.fn_name.clone(),
+            method: operation.http_method.to_lowercase(),
+            path: operation.path.clone(),
+        }
+    }
 
-        for route in routes {
-            let path_parts: Vec<&str> = route.path.split('/').filter(|s| !s.is_empty()).collect();
-            let module_path = path_parts[0].to_string();
-            debug!("Processing route: {} -> module: {}", route.path, module_path);
+    /// Markers attributing generated code to the operation or schema it was
+    /// rendered for.
+    fn origin_markers(api: &Api) -> Vec<OriginMarker> {
+        let operations = api.operations.iter().map(|operation| OriginMarker {
+            origin: Self::operation_origin(operation),
+            needles: vec![
+                format!("// {} {}", operation.http_method.to_uppercase(), operation.path),
+                format!("name=\"{}\"", operation.fn_name),
+                format!("fn {}(", operation.fn_name),
+                format!("{}:", operation.fn_name.to_uppercase()),
+                format!("enum {}Response", operation.fn_name.to_upper_camel_case()),
+            ],
+        });
+        let models = api.models.iter().map(|model| OriginMarker {
+            origin: Origin::Schema(model.spec_name.clone()),
+            needles: vec![
+                format!("/// {} model", model.name),
+                format!("struct {} ", model.name),
+            ],
+        });
+        operations.chain(models).collect()
+    }
 
-            if !modules.contains(&module_path) {
-                modules.push(module_path.clone());
-            }
+    /// The operations and schemas a file was rendered from.
+    fn file_origins(kind: &FileKind, api: &Api) -> Vec<Origin> {
+        let operations = |module: Option<&str>| {
+            api.operations
+                .iter()
+                .filter(|operation| module.is_none_or(|module| operation.module == module))
+                .map(Self::operation_origin)
+                .collect::<Vec<_>>()
+        };
+        let models = || {
+            api.models
+                .iter()
+                .map(|model| Origin::Schema(model.spec_name.clone()))
+        };
+        match kind {
+            FileKind::Handlers { module } => operations(Some(module)),
+            FileKind::Client | FileKind::Paths => operations(None),
+            FileKind::Models => models().collect(),
+            FileKind::OpenApi => operations(None).into_iter().chain(models()).collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Renders every file, before validation.
+    fn render_files(
+        openapi: &OpenAPI,
+        api: &Api,
+        config: &GeneratorConfig,
+    ) -> Result<Vec<GeneratedFile>, GeneratorError> {
+        info!("Starting OpenAPI translation");
+        let errors_translator = ErrorsTranslator::with_style(config.error_style);
+
+        debug!(
+            "Translated {} operations and {} models",
+            api.operations.len(),
+            api.models.len()
+        );
 
-            module_routes
-                .entry(module_path.clone())
-                .or_insert_with(Vec::new)
-                .push(route);
+        if config.target == Target::Client {
+            return Self::client_files(openapi, api);
         }
 
+        let security_schemes = api.security_schemes.clone();
+        debug!("Translated {} security schemes", security_schemes.len());
+
+        let errors = errors_translator.translate(&api.operations, &api.models);
+        debug!("Translated {} error variants", errors.len());
+
+        // Each operation lives in the module named after its first path segment
+        let route_modules = api.modules();
+        let mut modules = Vec::new();
+        let secured = |module: &String| {
+            api.module_operations(module)
+                .iter()
+                .any(|operation| !operation.security.is_empty())
+        };
+        let authenticated = route_modules.iter().any(secured);
+
         let mut files = Vec::new();
-        info!("Generating handler files for {} modules", module_routes.len());
+        info!("Generating handler files for {} modules", route_modules.len());
 
-        for (module, routes) in module_routes {
+        let mut sorted_modules = route_modules.clone();
+        sorted_modules.sort();
+        for module in sorted_modules {
             debug!("Generating handlers for module: {}", module);
-            let routes_without_tags = routes
-                .into_iter()
-                .map(|route| RouteWithoutTags {
-                    path: route.path,
-                    method: route.method,
-                    handler_name: route.handler_name,
-                    schema: route.schema,
-                    parameters: route.parameters,
-                    path_parameters: route.path_parameters,
-                    responses: route.responses,
-                    tag: route
-                        .tags
-                        .first()
-                        .cloned()
-                        .unwrap_or_else(|| "Default".to_string()),
-                })
-                .collect();
-
-            let functions = functions_translator.translate(openapi);
-
-            let mut folders = std::collections::HashSet::new();
-
-            for function in &functions {
-                folders.insert(function.folder.clone());
-            }
+            let operations = api.module_operations(&module);
 
             let mut template = AxumTemplate::new(
                 openapi,
-                routes_without_tags,
-                schemas.clone(),
-                functions,
+                api.models.clone(),
+                operations,
+                security_schemes.clone(),
             );
 
-            template.folders = folders;
+            template.paths = api.paths.clone();
+            template.error_style = config.error_style;
 
-            let content = match template.render() {
-                Ok(content) => {
-                    debug!("Successfully rendered template for module: {}", module);
-                    content
-                }
-                Err(e) => {
-                    error!("Failed to render template for module {}: {}", module, e);
-                    continue;
-                }
+            files.push(GeneratedFile::render(
+                format!("src/{}/handlers.rs", module),
+                FileKind::Handlers { module },
+                "axum_utoipa.rs.jinja",
+                &template,
+            )?);
+        }
+
+        let models_template = ModelsTemplate {
+            models: api.models.clone(),
+        };
+        files.push(GeneratedFile::render(
+            "src/models.rs",
+            FileKind::Models,
+            "models.rs.jinja",
+            &models_template,
+        )?);
+        modules.push("models".to_string());
+
+        let paths_template = PathsTemplate {
+            paths: api.paths.clone(),
+        };
+        files.push(GeneratedFile::render(
+            "src/paths.rs",
+            FileKind::Paths,
+            "paths.rs.jinja",
+            &paths_template,
+        )?);
+        modules.push("paths".to_string());
+
+        let errors_template = ErrorsTemplate {
+            errors,
+            problem: config.error_style == ErrorStyle::Problem,
+        };
+        files.push(GeneratedFile::render(
+            "src/errors.rs",
+            FileKind::Errors,
+            "errors.rs.jinja",
+            &errors_template,
+        )?);
+        modules.push("errors".to_string());
+
+        let openapi_template = OpenApiTemplate {
+            doc: ApiDocTranslator::new().translate(openapi, &api.operations, &api.models),
+        };
+        files.push(GeneratedFile::render(
+            "src/openapi.rs",
+            FileKind::OpenApi,
+            "openapi.rs.jinja",
+            &openapi_template,
+        )?);
+        modules.push("openapi".to_string());
+
+        if !security_schemes.is_empty() {
+            let security_template = SecurityTemplate {
+                schemes: security_schemes,
+                operations: api.operations.clone(),
             };
-            files.push((format!("src/{}/handlers.rs", module), content));
+            files.push(GeneratedFile::render(
+                "src/security.rs",
+                FileKind::Security,
+                "security.rs.jinja",
+                &security_template,
+            )?);
+            modules.push("security".to_string());
         }
 
-        let mod_template = ModTemplate { modules };
-        let mod_content = match mod_template.render() {
-            Ok(content) => {
-                debug!("Successfully rendered mod.rs template");
-                content
-            }
-            Err(e) => {
-                error!("Failed to render mod.rs template: {}", e);
-                return files;
+        if let Some(docs_config) = &config.docs {
+            let docs_template = DocsTemplate {
+                docs: DocsTranslator::new().translate(openapi, docs_config),
+            };
+            files.push(GeneratedFile::render(
+                "src/docs.rs",
+                FileKind::Docs,
+                "docs.rs.jinja",
+                &docs_template,
+            )?);
+            modules.push("docs".to_string());
+        }
+
+        let mut routers: Vec<String> = route_modules
+            .iter()
+            .map(|module| {
+                if secured(module) {
+                    format!(
+                        "crate::{}::handlers::create_router(handlers.clone(), authenticator.clone())",
+                        module
+                    )
+                } else {
+                    format!("crate::{}::handlers::create_router(handlers.clone())", module)
+                }
+            })
+            .collect();
+        for module in ["openapi", "docs"] {
+            if modules.iter().any(|m| m == module) {
+                routers.push(format!("crate::{}::router()", module));
             }
+        }
+
+        let mod_template = ModTemplate {
+            handlers: route_modules,
+            modules,
+            routers,
+            authenticated,
         };
-        files.push(("src/mod.rs".to_string(), mod_content));
+        files.push(GeneratedFile::render(
+            "src/mod.rs",
+            FileKind::Module,
+            "mod.rs.jinja",
+            &mod_template,
+        )?);
 
         info!("Completed OpenAPI translation, generated {} files", files.len());
-        files
+        Ok(files)
+    }
+
+    /// Generates the models and a `reqwest` client for `--target client`.
+    fn client_files(openapi: &OpenAPI, api: &Api) -> Result<Vec<GeneratedFile>, GeneratorError> {
+        let mut files = Vec::new();
+
+        files.push(GeneratedFile::render(
+            "src/models.rs",
+            FileKind::Models,
+            "models.rs.jinja",
+            &ModelsTemplate {
+                models: api.models.clone(),
+            },
+        )?);
+
+        let client_template = ClientTemplate {
+            operations: ClientTranslator::new().translate(&api.operations),
+            base_url: ClientTranslator::default_base_url(openapi),
+        };
+        debug!("Translated {} client operations", client_template.operations.len());
+        files.push(GeneratedFile::render(
+            "src/client.rs",
+            FileKind::Client,
+            "client.rs.jinja",
+            &client_template,
+        )?);
+
+        let mod_template = ModTemplate {
+            handlers: Vec::new(),
+            modules: vec!["models".to_string(), "client".to_string()],
+            routers: Vec::new(),
+            authenticated: false,
+        };
+        files.push(GeneratedFile::render(
+            "src/mod.rs",
+            FileKind::Module,
+            "mod.rs.jinja",
+            &mod_template,
+        )?);
+
+        info!("Completed client generation, generated {} files", files.len());
+        Ok(files)
     }
 }
@@ -1,43 +1,62 @@
-use clap::{Arg, Command};
+use clap::{Arg, ArgAction, ArgMatches, Command};
 
-use std::{fs, io, path::Path};
+use std::{fs, path::Path, time::Duration};
 
-mod file_utils;
-mod schema_generator;
-
-use crate::file_utils::openapi_from_file;
-
-/// Helper function to write content to a file, creating parent directories if needed
-fn write_file_with_path(path: &Path, content: &str) -> io::Result<()> {
-    if let Some(parent) = path.parent() {
-        fs::create_dir_all(parent)?;
-    }
-    fs::write(path, content)
-}
+use openapi_axum_generator::config::{DocsConfig, GeneratorConfig};
+use openapi_axum_generator::schema_generator;
+use openapi_axum_generator::watch::{self, WatchOptions};
+use openapi_axum_generator::Generator;
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     init();
     let matches = Command::new("openapi-axum-generator")
         .version("0.1.0")
         .about("Generates Axum server code from OpenAPI specification")
-        .arg(
-            Arg::new("input")
-                .short('i')
-                .long("input")
-                .value_name("FILE")
-                .help("Path to OpenAPI JSON file")
-                .required(true),
+        .args(generation_args())
+        .args_conflicts_with_subcommands(true)
+        .subcommand(
+            Command::new("check")
+                .about("Exits non-zero with a diff if the output directory is not up to date")
+                .args(generation_args()),
         )
-        .arg(
-            Arg::new("output")
-                .short('o')
-                .long("output")
-                .value_name("DIR")
-                .help("Output directory for generated files")
-                .required(false),
+        .subcommand(
+            Command::new("watch")
+                .about("Regenerates whenever the spec or a file it references changes")
+                .args(generation_args())
+                .arg(
+                    Arg::new("debounce")
+                        .long("debounce")
+                        .value_name("MS")
+                        .help("Milliseconds changes must settle before regenerating")
+                        .value_parser(clap::value_parser!(u64))
+                        .default_value("200"),
+                ),
         )
         .get_matches();
 
+    let (matches, check) = match matches.subcommand() {
+        Some(("check", check_matches)) => (check_matches, true),
+        Some(("watch", watch_matches)) => {
+            let options = WatchOptions {
+                input: watch_matches
+                    .get_one::<String>("input")
+                    .expect("input is required")
+                    .into(),
+                output_dir: watch_matches
+                    .get_one::<String>("output")
+                    .expect("output is required")
+                    .into(),
+                config: config_from_matches(watch_matches)?,
+                remove_stale: watch_matches.get_flag("remove-stale"),
+                debounce: Duration::from_millis(
+                    *watch_matches.get_one::<u64>("debounce").expect("debounce has a default"),
+                ),
+            };
+            return watch::watch(&options);
+        }
+        _ => (&matches, false),
+    };
+
     let input_file = matches
         .get_one::<String>("input")
         .expect("input is required");
@@ -45,31 +64,140 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         .get_one::<String>("output")
         .expect("output is required");
 
+    let config = config_from_matches(matches)?;
+
     // log the input and output
     log::debug!("### Input file: {:?}", input_file);
     
+    // Load the spec and generate files
+    let project = Generator::builder()
+        .spec(Path::new(input_file))
+        .config(config)
+        .build()?
+        .generate()?;
+
+    if check {
+        // Compare in memory only; nothing is written
+        let changes = project.check(Path::new(output_dir), matches.get_flag("remove-stale"))?;
+        if changes.is_empty() {
+            println!("{} is up to date", output_dir);
+            return Ok(());
+        }
+        for change in &changes {
+            print!("{}", change);
+        }
+        eprintln!("{} generated files in {} are out of date", changes.len(), output_dir);
+        std::process::exit(1);
+    }
+
     // Create output directory
     fs::create_dir_all(output_dir)?;
 
-    // Load and parse OpenAPI spec
-    let openapi_spec = openapi_from_file(input_file)?;
-
     // Generate schema types
     schema_generator::generate_types_from_schemas(output_dir)?;
 
-    // Generate files
-    let files = openapi_axum_generator::AxumTemplate::from_openapi(&openapi_spec);
-
-    // Write all generated files using helper function
-    for (file_path, content) in files.into_iter() {
-        let full_path = Path::new(output_dir).join(file_path);
-        write_file_with_path(&full_path, &content)?;
+    // Write only what changed, so unchanged files keep their timestamps
+    let report = project.write(Path::new(output_dir), matches.get_flag("remove-stale"))?;
+    for path in &report.stale {
+        log::warn!("Stale generated file kept: {} (use --remove-stale)", path.display());
     }
 
-    println!("Successfully generated Axum server code in {}", output_dir);
+    println!("Successfully generated Axum server code in {} ({})", output_dir, report);
     Ok(())
 }
 
+/// The options shared by generation and `check`.
+fn generation_args() -> Vec<Arg> {
+    vec![
+        Arg::new("input")
+            .short('i')
+            .long("input")
+            .value_name("FILE")
+            .help("Path to OpenAPI JSON file")
+            .required(true),
+        Arg::new("output")
+            .short('o')
+            .long("output")
+            .value_name("DIR")
+            .help("Output directory for generated files")
+            .required(false),
+        Arg::new("error-style")
+            .long("error-style")
+            .value_name("STYLE")
+            .help("Error body style: `declared` (spec schemas) or `problem` (RFC 7807)")
+            .value_parser(["declared", "problem"])
+            .default_value("declared"),
+        Arg::new("target")
+            .long("target")
+            .value_name("TARGET")
+            .help("What to generate: `server` (Axum) or `client` (reqwest)")
+            .value_parser(["server", "client"])
+            .default_value("server"),
+        Arg::new("map-type")
+            .long("map-type")
+            .value_name("MAP")
+            .help("Map for `additionalProperties`: `hash-map` or `btree-map` (ordered output)")
+            .value_parser(["hash-map", "btree-map"])
+            .default_value("hash-map"),
+        Arg::new("docs")
+            .long("docs")
+            .value_name("UI")
+            .help("Generate interactive API documentation with this UI")
+            .value_parser(["swagger-ui", "redoc", "scalar"]),
+        Arg::new("docs-path")
+            .long("docs-path")
+            .value_name("PATH")
+            .help("Route the documentation is mounted at")
+            .default_value("/docs"),
+        Arg::new("docs-spec")
+            .long("docs-spec")
+            .value_name("SPEC")
+            .help("Document rendered by the docs: `generated` (ApiDoc) or `original` (input spec)")
+            .value_parser(["generated", "original"])
+            .default_value("generated"),
+        Arg::new("docs-assets")
+            .long("docs-assets")
+            .value_name("DIR")
+            .help("Embed the docs UI assets vendored in this directory, relative to the generated crate, instead of loading them from a CDN"),
+        Arg::new("remove-stale")
+            .long("remove-stale")
+            .help("Delete files generated by a previous run that are no longer generated")
+            .action(ArgAction::SetTrue),
+    ]
+}
+
+/// Builds the generator options from the parsed command line.
+fn config_from_matches(matches: &ArgMatches) -> Result<GeneratorConfig, Box<dyn std::error::Error>> {
+    Ok(GeneratorConfig {
+        error_style: matches
+            .get_one::<String>("error-style")
+            .expect("error-style has a default")
+            .parse()?,
+        target: matches
+            .get_one::<String>("target")
+            .expect("target has a default")
+            .parse()?,
+        docs: match matches.get_one::<String>("docs") {
+            Some(ui) => Some(DocsConfig {
+                ui: ui.parse()?,
+                path: matches
+                    .get_one::<String>("docs-path")
+                    .expect("docs-path has a default")
+                    .clone(),
+                spec: matches
+                    .get_one::<String>("docs-spec")
+                    .expect("docs-spec has a default")
+                    .parse()?,
+                assets_dir: matches.get_one::<String>("docs-assets").cloned(),
+            }),
+            None => None,
+        },
+        map_type: matches
+            .get_one::<String>("map-type")
+            .expect("map-type has a default")
+            .parse()?,
+    })
+}
 
 fn init() {
         let _ = env_logger::builder()
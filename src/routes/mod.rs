@@ -1,5 +0,0 @@
-pub mod keywords;
-pub mod models;
-pub mod translator;
-
-pub use models::*;
@@ -1,9 +0,0 @@
-use crate::test_utils::TestUtils;
-
-#[test]
-fn test_uspto_routes() {
-    // Test implementation here
-    
-    // Clean up after test
-    TestUtils::cleanup_generated_files().expect("Failed to clean up generated files");
-}
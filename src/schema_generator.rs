@@ -1,11 +1,13 @@
 use std::fs;
 use std::path::Path;
-use schemars;
 use typify::{TypeSpace, TypeSpaceSettings};
 
 pub fn generate_types_from_schemas(output_dir: &str) -> Result<(), Box<dyn std::error::Error>> {
     let schema_dir = Path::new("src/test_data/schemas");
-    
+    if !schema_dir.exists() {
+        return Ok(());
+    }
+
     // Create output directory if it doesn't exist
     let output_dir = Path::new(output_dir).join("generated_types");
     fs::create_dir_all(&output_dir)?;
@@ -41,19 +43,10 @@ pub fn generate_types_from_schemas(output_dir: &str) -> Result<(), Box<dyn std::
                     .to_string() + ".rs"
             );
             
-            // Write the generated types
+            // Format the generated types in-process and write them
             let tokens = type_space.to_stream();
-            let generated = tokens.to_string();
-            fs::write(&output_path, &generated)?;
-            
-            // Format the generated file using rustfmt
-            let status = std::process::Command::new("rustfmt")
-                .arg(&output_path)
-                .status()?;
-            
-            if !status.success() {
-                return Err(format!("Failed to format {} with rustfmt", output_path.display()).into());
-            }
+            let generated = crate::formatter::format_rust(&tokens.to_string())?;
+            fs::write(output_path, &generated)?;
         }
     }
     
@@ -0,0 +1,277 @@
+use std::collections::{BTreeMap, HashSet};
+
+use heck::ToUpperCamelCase;
+use openapiv3::{
+    APIKeyLocation, OAuth2Flows, OpenAPI, SecurityRequirement, SecurityScheme,
+};
+use serde::Serialize;
+
+use crate::file_utils::json_pointer;
+use crate::ir::resolve::Resolver;
+use crate::ir::Diagnostic;
+
+/// The kind of credential a security scheme expects.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub enum SchemeKind {
+    /// An API key carried in a header, query parameter or cookie.
+    ApiKey,
+    /// HTTP `Authorization: Basic ...`.
+    HttpBasic,
+    /// HTTP `Authorization: Bearer ...`.
+    HttpBearer,
+    /// OAuth2 access token, sent as a bearer token.
+    OAuth2,
+    /// OpenID Connect ID/access token, sent as a bearer token.
+    OpenIdConnect,
+}
+
+/// Describes a security scheme declared in `components.securitySchemes`.
+#[derive(Debug, Clone, Serialize)]
+pub struct SecuritySchemeSignature {
+    /// The scheme name as declared in the spec (e.g. "api_key").
+    pub name: String,
+
+    /// The Rust enum variant generated for this scheme (e.g. "ApiKey").
+    pub variant: String,
+
+    /// What kind of credential the scheme expects.
+    pub kind: SchemeKind,
+
+    /// Where an API key is read from ("header", "query" or "cookie").
+    pub location: Option<String>,
+
+    /// The header, query parameter or cookie name holding an API key.
+    pub key_name: Option<String>,
+
+    /// The scopes declared across all OAuth2 flows.
+    pub scopes: Vec<String>,
+
+    /// Description of the scheme, used in doc comments.
+    pub description: Option<String>,
+}
+
+/// One scheme within a security requirement, with the scopes it needs.
+#[derive(Debug, Clone, Serialize)]
+pub struct RequiredScheme {
+    /// The scheme name as declared in the spec.
+    pub name: String,
+
+    /// The Rust enum variant of the scheme.
+    pub variant: String,
+
+    /// Scopes the credential must carry (OAuth2/OpenID Connect only).
+    pub scopes: Vec<String>,
+}
+
+/// A single security requirement object: every listed scheme must be satisfied.
+/// An empty requirement allows anonymous access.
+#[derive(Debug, Clone, Serialize)]
+pub struct SecurityRequirementSignature {
+    pub schemes: Vec<RequiredScheme>,
+}
+
+pub struct SecurityTranslator;
+
+impl Default for SecurityTranslator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SecurityTranslator {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Collects the security schemes declared in `components.securitySchemes`,
+    /// following references with `resolver`. Schemes whose reference does not
+    /// resolve are left out.
+    pub fn translate(&self, openapi: &OpenAPI, resolver: &Resolver) -> Vec<SecuritySchemeSignature> {
+        let variants = scheme_variants(openapi, resolver);
+        declared_schemes(openapi, resolver)
+            .into_iter()
+            .filter_map(|(name, scheme)| {
+                let scheme = scheme.ok()?;
+                Some(Self::translate_scheme(&name, &variants[&name], &scheme))
+            })
+            .collect()
+    }
+
+    fn translate_scheme(
+        name: &str,
+        variant: &str,
+        scheme: &SecurityScheme,
+    ) -> SecuritySchemeSignature {
+        let mut signature = SecuritySchemeSignature {
+            name: name.to_string(),
+            variant: variant.to_string(),
+            kind: SchemeKind::ApiKey,
+            location: None,
+            key_name: None,
+            scopes: Vec::new(),
+            description: None,
+        };
+
+        match scheme {
+            SecurityScheme::APIKey {
+                location,
+                name: key_name,
+                description,
+                ..
+            } => {
+                signature.location = Some(
+                    match location {
+                        APIKeyLocation::Header => "header",
+                        APIKeyLocation::Query => "query",
+                        APIKeyLocation::Cookie => "cookie",
+                    }
+                    .to_string(),
+                );
+                signature.key_name = Some(key_name.clone());
+                signature.description = description.clone();
+            }
+            SecurityScheme::HTTP {
+                scheme: http_scheme,
+                description,
+                ..
+            } => {
+                // Anything other than basic is sent in the Authorization header as a token
+                signature.kind = if http_scheme.eq_ignore_ascii_case("basic") {
+                    SchemeKind::HttpBasic
+                } else {
+                    SchemeKind::HttpBearer
+                };
+                signature.description = description.clone();
+            }
+            SecurityScheme::OAuth2 {
+                flows, description, ..
+            } => {
+                signature.kind = SchemeKind::OAuth2;
+                signature.scopes = oauth2_scopes(flows);
+                signature.description = description.clone();
+            }
+            SecurityScheme::OpenIDConnect { description, .. } => {
+                signature.kind = SchemeKind::OpenIdConnect;
+                signature.description = description.clone();
+            }
+        }
+
+        signature
+    }
+}
+
+/// Resolves the effective security of the operation at `pointer`: its own
+/// `security` if present (where `security: []` opts out), otherwise the
+/// global `security`. A requirement naming a scheme that is not declared, or
+/// whose reference does not resolve, can never be met: it is dropped and
+/// reported.
+pub fn operation_security(
+    openapi: &OpenAPI,
+    resolver: &Resolver,
+    operation_security: Option<&Vec<SecurityRequirement>>,
+    pointer: &str,
+) -> (Vec<SecurityRequirementSignature>, Vec<Diagnostic>) {
+    let (requirements, pointer) = match operation_security {
+        Some(requirements) => (requirements, format!("{}/security", pointer)),
+        None => match &openapi.security {
+            Some(requirements) => (requirements, json_pointer(["security"])),
+            None => return (Vec::new(), Vec::new()),
+        },
+    };
+    let declared = declared_schemes(openapi, resolver);
+    let variants = scheme_variants(openapi, resolver);
+
+    let mut signatures = Vec::new();
+    let mut diagnostics = Vec::new();
+    for (i, requirement) in requirements.iter().enumerate() {
+        let mut schemes = Vec::new();
+        for (name, scopes) in requirement {
+            let message = match declared.iter().find(|(declared, _)| declared == name) {
+                None => format!("security scheme `{}` is not declared", name),
+                Some((_, Err(message))) => format!("security scheme `{}`: {}", name, message),
+                Some((_, Ok(_))) => {
+                    schemes.push(RequiredScheme {
+                        name: name.clone(),
+                        variant: variants[name].clone(),
+                        scopes: scopes.clone(),
+                    });
+                    continue;
+                }
+            };
+            diagnostics.push(Diagnostic {
+                pointer: format!("{}/{}", pointer, i),
+                message,
+            });
+        }
+        if schemes.len() == requirement.len() {
+            signatures.push(SecurityRequirementSignature { schemes });
+        }
+    }
+    (signatures, diagnostics)
+}
+
+/// The schemes in `components.securitySchemes`, in declaration order, with
+/// references followed.
+fn declared_schemes(
+    openapi: &OpenAPI,
+    resolver: &Resolver,
+) -> Vec<(String, Result<SecurityScheme, String>)> {
+    openapi
+        .components
+        .iter()
+        .flat_map(|components| &components.security_schemes)
+        .map(|(name, scheme)| (name.clone(), resolver.resolve(scheme)))
+        .collect()
+}
+
+/// The enum variant of every scheme that resolves, keyed by scheme name.
+/// Names that camel-case alike (e.g. "api_key" and "apiKey") are numbered in
+/// declaration order.
+fn scheme_variants(openapi: &OpenAPI, resolver: &Resolver) -> BTreeMap<String, String> {
+    let mut variants = BTreeMap::new();
+    let mut taken = HashSet::new();
+    let declared = declared_schemes(openapi, resolver);
+    let names = declared
+        .iter()
+        .filter(|(_, scheme)| scheme.is_ok())
+        .map(|(name, _)| name);
+    for name in names {
+        let base = scheme_variant(name);
+        let mut variant = base.clone();
+        let mut suffix = 2;
+        while !taken.insert(variant.clone()) {
+            variant = format!("{}{}", base, suffix);
+            suffix += 1;
+        }
+        variants.insert(name.clone(), variant);
+    }
+    variants
+}
+
+/// Converts a scheme name into the variant name used by the generated enum.
+fn scheme_variant(name: &str) -> String {
+    name.to_upper_camel_case()
+}
+
+/// The scopes declared across all OAuth2 flows, in order of first appearance.
+fn oauth2_scopes(flows: &OAuth2Flows) -> Vec<String> {
+    let declared = [
+        flows.implicit.as_ref().map(|flow| &flow.scopes),
+        flows.password.as_ref().map(|flow| &flow.scopes),
+        flows.client_credentials.as_ref().map(|flow| &flow.scopes),
+        flows.authorization_code.as_ref().map(|flow| &flow.scopes),
+    ];
+    let mut scopes: Vec<String> = Vec::new();
+    for flow_scopes in declared.into_iter().flatten() {
+        for scope in flow_scopes.keys() {
+            if !scopes.contains(scope) {
+                scopes.push(scope.clone());
+            }
+        }
+    }
+    scopes
+}
+
+#[cfg(test)]
+#[path = "tests/security_translator_test.rs"]
+mod security_translator_tests;
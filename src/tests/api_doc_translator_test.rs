@@ -0,0 +1,135 @@
+#[cfg(test)]
+mod tests {
+    use crate::api_doc_translator::{raw_string_literal, ApiDocTranslator};
+    use crate::file_utils;
+    use crate::ir::Api;
+    use crate::AxumTemplate;
+    use openapiv3::OpenAPI;
+    use std::path::PathBuf;
+
+    fn load_petstore() -> OpenAPI {
+        file_utils::openapi_from_file(PathBuf::from("src/test_data/petstore.json")).unwrap()
+    }
+
+    #[test]
+    fn test_translate_petstore_api_doc() {
+        let openapi = load_petstore();
+        let api = Api::from_openapi(&openapi);
+        let doc = ApiDocTranslator::new().translate(&openapi, &api.operations, &api.models);
+
+        let mut paths = doc.paths.clone();
+        paths.sort();
+        assert_eq!(
+            paths,
+            vec![
+                "crate::pets::handlers::create_pets",
+                "crate::pets::handlers::list_pets",
+                "crate::pets::handlers::show_pet_by_id",
+            ]
+        );
+        assert!(doc.schemas.contains(&"crate::models::Pet".to_string()));
+        assert!(doc.schemas.contains(&"crate::models::Error".to_string()));
+        assert_eq!(doc.route, "/openapi.json");
+
+        // `$ref`s contain `"#`, so the literal needs two hashes
+        assert!(doc.source_spec.starts_with("r##\""));
+
+        // The embedded spec round-trips to the input
+        let literal = doc.source_spec.trim_start_matches("r##\"").trim_end_matches("\"##");
+        let embedded: OpenAPI = serde_json::from_str(literal).unwrap();
+        assert_eq!(embedded, openapi);
+    }
+
+    #[test]
+    fn test_raw_string_literal_hashes() {
+        assert_eq!(raw_string_literal("{}"), "r#\"{}\"#");
+        assert_eq!(
+            raw_string_literal("{\"a\": \"#b\"}"),
+            "r##\"{\"a\": \"#b\"}\"##"
+        );
+    }
+
+    #[test]
+    fn test_openapi_module_generation() {
+        let openapi = load_petstore();
+        let files = AxumTemplate::from_openapi(&openapi).unwrap();
+
+        let (_, doc) = files
+            .iter()
+            .find(|(path, _)| path == "src/openapi.rs")
+            .expect("openapi.rs not generated");
+        assert!(doc.contains("#[derive(OpenApi)]"));
+        assert!(doc.contains("        crate::pets::handlers::list_pets,"));
+        assert!(doc.contains(
+            "components(schemas(crate::models::Pet, crate::models::Pets, crate::models::Error)),"
+        ));
+        assert!(doc.contains("pub const OPENAPI_JSON_PATH: &str = \"/openapi.json\";"));
+        assert!(doc.contains("fn api_doc_matches_source_spec()"));
+
+        let (_, mod_rs) = files.iter().find(|(path, _)| path == "src/mod.rs").unwrap();
+        assert!(mod_rs.contains("pub mod openapi;"));
+    }
+
+    #[test]
+    fn test_paths_name_handler_functions() {
+        let openapi = load_petstore();
+        let api = Api::from_openapi(&openapi);
+        let doc = ApiDocTranslator::new().translate(&openapi, &api.operations, &api.models);
+        let files = AxumTemplate::from_openapi(&openapi).unwrap();
+
+        let (_, handlers) = files
+            .iter()
+            .find(|(path, _)| path == "src/pets/handlers.rs")
+            .unwrap();
+        assert!(handlers.contains("pub trait Handlers: Clone + Send + Sync + 'static {"));
+        for path in &doc.paths {
+            let name = path.trim_start_matches("crate::pets::handlers::");
+            assert!(
+                handlers.contains(&format!("pub async fn {}<H: Handlers>(", name)),
+                "no handler for {}",
+                path
+            );
+        }
+        assert!(handlers.contains("status = \"default\""));
+    }
+
+    #[test]
+    fn test_no_components_without_models() {
+        let openapi: OpenAPI = serde_json::from_str(
+            r#"{
+                "openapi": "3.0.3",
+                "info": {"title": "Health", "version": "1"},
+                "paths": {"/health": {"get": {"responses": {"204": {"description": "ok"}}}}}
+            }"#,
+        )
+        .unwrap();
+        let files = AxumTemplate::from_openapi(&openapi).unwrap();
+
+        let (_, doc) = files.iter().find(|(path, _)| path == "src/openapi.rs").unwrap();
+        assert!(doc.contains("crate::health::handlers::"));
+        assert!(!doc.contains("components("));
+    }
+
+    #[test]
+    fn test_schema_names_keep_spec_keys() {
+        let openapi: OpenAPI = file_utils::openapi_from_file(PathBuf::from(
+            "src/test_data/schema_names.json",
+        ))
+        .unwrap();
+        let api = Api::from_openapi(&openapi);
+        let doc = ApiDocTranslator::new().translate(&openapi, &api.operations, &api.models);
+        assert_eq!(
+            doc.schema_names,
+            vec![
+                ("DataSetList".to_string(), "dataSetList".to_string()),
+                ("DefinitionsFilter".to_string(), "definitions-Filter".to_string()),
+            ]
+        );
+
+        // PascalCase keys need no renaming
+        let openapi = load_petstore();
+        let api = Api::from_openapi(&openapi);
+        let doc = ApiDocTranslator::new().translate(&openapi, &api.operations, &api.models);
+        assert!(doc.schema_names.is_empty());
+    }
+}
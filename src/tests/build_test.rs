@@ -0,0 +1,64 @@
+#[cfg(test)]
+mod tests {
+    use crate::build::{generate_into, single_module, tracked_files, MOD_FILE};
+    use crate::config::GeneratorConfig;
+    use crate::generator::Generator;
+    use std::fs;
+    use std::path::{Path, PathBuf};
+
+    const PETSTORE: &str = "src/test_data/petstore.json";
+
+    /// Whether every module in `items` is inline.
+    fn all_inline(items: &[syn::Item]) -> bool {
+        items.iter().all(|item| match item {
+            syn::Item::Mod(module) => module
+                .content
+                .as_ref()
+                .is_some_and(|(_, items)| all_inline(items)),
+            _ => true,
+        })
+    }
+
+    #[test]
+    fn test_single_module_inlines_files() {
+        let project = Generator::builder().spec(PETSTORE).build().unwrap().generate().unwrap();
+        let module = single_module(&project).unwrap();
+
+        let file = syn::parse_file(&module).unwrap();
+        assert!(all_inline(&file.items));
+        assert!(module.contains("pub mod pets {\n    pub mod handlers {\n"));
+        assert!(module.contains("pub mod models {\n"));
+        assert!(module.contains(
+            "pub fn app<H>(handlers: H) -> axum::Router\nwhere\n    H: crate::pets::handlers::Handlers,\n{"
+        ));
+        // Declared in `src/mod.rs` but never generated
+        assert!(!module.contains("_tests"));
+    }
+
+    #[test]
+    fn test_generate_into_out_dir() {
+        let out_dir = tempfile::tempdir().unwrap();
+        let path =
+            generate_into(Path::new(PETSTORE), GeneratorConfig::default(), out_dir.path()).unwrap();
+        assert_eq!(path, out_dir.path().join(MOD_FILE));
+        let modified = fs::metadata(&path).unwrap().modified().unwrap();
+
+        // An unchanged spec leaves the file alone
+        generate_into(Path::new(PETSTORE), GeneratorConfig::default(), out_dir.path()).unwrap();
+        assert_eq!(fs::metadata(&path).unwrap().modified().unwrap(), modified);
+    }
+
+    #[test]
+    fn test_tracked_files_include_external_refs() {
+        let spec = Path::new("src/test_data/ssv_openapi_bundle.yaml");
+        let tracked = tracked_files(spec);
+        assert_eq!(tracked[0], spec);
+        assert!(tracked.contains(&PathBuf::from("src/test_data/./healthcheck.json")));
+
+        // Missing specs are still tracked, so creating them reruns the build
+        assert_eq!(
+            tracked_files(Path::new("missing.yaml")),
+            vec![PathBuf::from("missing.yaml")]
+        );
+    }
+}
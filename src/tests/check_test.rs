@@ -0,0 +1,80 @@
+#[cfg(test)]
+mod tests {
+    use crate::check::{check_files, ChangeKind};
+    use crate::writer::write_files;
+    use std::fs;
+
+    fn files(entries: &[(&str, &str)]) -> Vec<(String, String)> {
+        entries
+            .iter()
+            .map(|(path, content)| (path.to_string(), content.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_check_up_to_date() {
+        let dir = tempfile::tempdir().unwrap();
+        let generated = files(&[("src/mod.rs", "pub mod pets;\n")]);
+        write_files(dir.path(), &generated, false).unwrap();
+
+        assert!(check_files(dir.path(), &generated, true).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_check_reports_unified_diff() {
+        let dir = tempfile::tempdir().unwrap();
+        let generated = files(&[
+            ("src/mod.rs", "pub mod pets;\npub mod stores;\n"),
+            ("src/stores/handlers.rs", "// stores\n"),
+        ]);
+        write_files(dir.path(), &generated, false).unwrap();
+
+        let regenerated = files(&[
+            ("src/mod.rs", "pub mod pets;\npub mod users;\n"),
+            ("src/users/handlers.rs", "// users\n"),
+        ]);
+        let changes = check_files(dir.path(), &regenerated, false).unwrap();
+        let kinds: Vec<_> = changes.iter().map(|c| (c.path.as_str(), c.kind)).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                ("src/mod.rs", ChangeKind::Updated),
+                ("src/users/handlers.rs", ChangeKind::Created),
+            ]
+        );
+        assert_eq!(
+            changes[0].diff,
+            "--- a/src/mod.rs\n+++ b/src/mod.rs\n@@ -1,2 +1,2 @@\n pub mod pets;\n-pub mod stores;\n+pub mod users;\n"
+        );
+        assert!(changes[1].diff.starts_with("--- /dev/null\n+++ b/src/users/handlers.rs\n"));
+
+        // Stale files only count when they would be removed
+        let changes = check_files(dir.path(), &regenerated, true).unwrap();
+        assert_eq!(changes[2].path, "src/stores/handlers.rs");
+        assert_eq!(changes[2].kind, ChangeKind::Deleted);
+
+        // Nothing was written
+        assert_eq!(
+            fs::read_to_string(dir.path().join("src/mod.rs")).unwrap(),
+            "pub mod pets;\npub mod stores;\n"
+        );
+        assert!(!dir.path().join("src/users").exists());
+    }
+
+    #[test]
+    fn test_check_ignores_user_code() {
+        let dir = tempfile::tempdir().unwrap();
+        let generated = files(&[(
+            "src/pets/handlers.rs",
+            "// <user-code name=\"list_pets\">\n// </user-code>\n",
+        )]);
+        write_files(dir.path(), &generated, false).unwrap();
+        fs::write(
+            dir.path().join("src/pets/handlers.rs"),
+            "// <user-code name=\"list_pets\">\npub async fn list_pets() {}\n// </user-code>\n",
+        )
+        .unwrap();
+
+        assert!(check_files(dir.path(), &generated, false).unwrap().is_empty());
+    }
+}
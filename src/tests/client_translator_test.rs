@@ -0,0 +1,175 @@
+#[cfg(test)]
+mod tests {
+    use crate::client_translator::ClientTranslator;
+    use crate::config::{GeneratorConfig, Target};
+    use crate::file_utils;
+    use crate::ir::{Api, ParameterLocation};
+    use crate::AxumTemplate;
+    use openapiv3::OpenAPI;
+    use std::path::PathBuf;
+
+    fn load_petstore() -> OpenAPI {
+        file_utils::openapi_from_file(PathBuf::from("src/test_data/petstore.json")).unwrap()
+    }
+
+    #[test]
+    fn test_translate_petstore_operations() {
+        let openapi = load_petstore();
+        let operations = ClientTranslator::new().translate(&Api::from_openapi(&openapi).operations);
+        assert_eq!(operations.len(), 3);
+
+        let list_pets = operations.iter().find(|op| op.fn_name == "list_pets").unwrap();
+        assert_eq!(list_pets.http_method, "GET");
+        assert_eq!(list_pets.path_segments, vec!["\"pets\""]);
+        assert_eq!(list_pets.args.len(), 1);
+        assert_eq!(list_pets.args[0].rust_type, "Option<i32>");
+        assert_eq!(list_pets.args[0].location, ParameterLocation::Query);
+        assert_eq!(list_pets.response_enum, "ListPetsResponse");
+        let variants: Vec<_> = list_pets.responses.iter().map(|r| r.variant.as_str()).collect();
+        assert_eq!(variants, vec!["Ok", "Default"]);
+        assert_eq!(list_pets.responses[0].rust_type.as_deref(), Some("Pets"));
+        assert!(list_pets.has_default);
+
+        let show_pet = operations
+            .iter()
+            .find(|op| op.fn_name == "show_pet_by_id")
+            .unwrap();
+        assert_eq!(show_pet.args[0].name, "pet_id");
+        assert_eq!(show_pet.args[0].wire_name, "petId");
+        assert_eq!(show_pet.path_segments, vec!["\"pets\"", "&pet_id.to_string()"]);
+
+        assert_eq!(
+            ClientTranslator::default_base_url(&openapi).as_deref(),
+            Some("http://petstore.swagger.io/api")
+        );
+    }
+
+    #[test]
+    fn test_translate_headers_and_templated_segments() {
+        let openapi: OpenAPI = serde_json::from_value(serde_json::json!({
+            "openapi": "3.0.0",
+            "info": { "title": "Files", "version": "1.0.0" },
+            "servers": [{
+                "url": "{scheme}://files.example.com",
+                "variables": { "scheme": { "default": "https" } }
+            }],
+            "paths": {
+                "/files/{type}/{name}.json": {
+                    "get": {
+                        "operationId": "getFile",
+                        "parameters": [
+                            {
+                                "name": "type", "in": "path", "required": true,
+                                "schema": { "type": "string" }
+                            },
+                            {
+                                "name": "X-Request-ID", "in": "header", "required": true,
+                                "schema": { "type": "string" }
+                            },
+                            {
+                                "name": "tags", "in": "query",
+                                "schema": { "type": "array", "items": { "type": "string" } }
+                            }
+                        ],
+                        "responses": { "204": { "description": "Found" } }
+                    }
+                }
+            }
+        }))
+        .unwrap();
+
+        let operations = ClientTranslator::new().translate(&Api::from_openapi(&openapi).operations);
+        let get_file = &operations[0];
+
+        // `name` is undeclared but still needed for the URL
+        let args: Vec<_> = get_file.args.iter().map(|a| a.name.as_str()).collect();
+        assert_eq!(args, vec!["name", "r#type", "tags", "x_request_id"]);
+        assert!(get_file.args[2].repeated);
+        assert_eq!(get_file.args[2].rust_type, "Option<Vec<String>>");
+        assert_eq!(
+            get_file.path_segments,
+            vec!["\"files\"", "&r#type.to_string()", "&format!(\"{}.json\", name)"]
+        );
+        assert!(!get_file.has_default);
+
+        assert_eq!(
+            ClientTranslator::default_base_url(&openapi).as_deref(),
+            Some("https://files.example.com")
+        );
+    }
+
+    #[test]
+    fn test_client_target_generation() {
+        let openapi = load_petstore();
+        let config = GeneratorConfig {
+            target: Target::Client,
+            ..Default::default()
+        };
+        let files = AxumTemplate::from_openapi_with_config(&openapi, &config).unwrap();
+
+        let paths: Vec<_> = files.iter().map(|(path, _)| path.as_str()).collect();
+        assert_eq!(paths, vec!["src/models.rs", "src/client.rs", "src/mod.rs"]);
+
+        let (_, client) = &files[1];
+        assert!(client.contains("pub const DEFAULT_BASE_URL: &str = \"http://petstore.swagger.io/api\";"));
+        assert!(client.contains("pub async fn show_pet_by_id(\n        &self,\n        pet_id: String,\n    ) -> Result<ShowPetByIdResponse, ClientError> {"));
+        assert!(client.contains("201 => CreatePetsResponse::Created,"));
+        assert!(client.contains("_ => ListPetsResponse::Default(status, response.json().await?),"));
+    }
+
+    #[test]
+    fn test_status_ranges() {
+        let openapi: OpenAPI = serde_json::from_value(serde_json::json!({
+            "openapi": "3.0.0",
+            "info": { "title": "Pets", "version": "1.0.0" },
+            "paths": {
+                "/pets": {
+                    "get": {
+                        "operationId": "listPets",
+                        "responses": {
+                            "4XX": {
+                                "description": "Rejected",
+                                "content": {
+                                    "application/json": { "schema": { "type": "string" } }
+                                }
+                            },
+                            "200": { "description": "Listed" },
+                            "5XX": { "description": "Failed" }
+                        }
+                    }
+                }
+            }
+        }))
+        .unwrap();
+
+        let operations =
+            ClientTranslator::new().translate(&Api::from_openapi(&openapi).operations);
+        let responses: Vec<_> = operations[0]
+            .responses
+            .iter()
+            .map(|r| (r.variant.as_str(), r.status, r.range.as_deref()))
+            .collect();
+        assert_eq!(
+            responses,
+            vec![
+                ("ClientError", None, Some("400..=499")),
+                ("Ok", Some(200), None),
+                ("ServerError", None, Some("500..=599")),
+            ]
+        );
+
+        // Exact codes are matched before ranges, and 200 only once
+        let config = GeneratorConfig {
+            target: Target::Client,
+            ..Default::default()
+        };
+        let files = AxumTemplate::from_openapi_with_config(&openapi, &config).unwrap();
+        let (_, client) = &files[1];
+        assert!(client.contains("ClientError(StatusCode, String),"));
+        assert_eq!(client.matches("200 =>").count(), 1);
+        let exact = client.find("200 => ListPetsResponse::Ok,").unwrap();
+        let range = client.find("400..=499 =>").unwrap();
+        assert!(exact < range);
+        assert!(client.contains("500..=599 => ListPetsResponse::ServerError(status),"));
+    }
+}
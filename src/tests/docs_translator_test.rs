@@ -0,0 +1,132 @@
+#[cfg(test)]
+mod tests {
+    use crate::config::{DocsConfig, DocsSpec, DocsUi, GeneratorConfig};
+    use crate::docs_translator::DocsTranslator;
+    use crate::file_utils;
+    use crate::AxumTemplate;
+    use openapiv3::OpenAPI;
+    use std::path::PathBuf;
+
+    fn load_petstore() -> OpenAPI {
+        file_utils::openapi_from_file(PathBuf::from("src/test_data/petstore.json")).unwrap()
+    }
+
+    #[test]
+    fn test_translate_swagger_ui() {
+        let openapi = load_petstore();
+        let docs = DocsTranslator::new().translate(&openapi, &DocsConfig::default());
+
+        assert_eq!(docs.ui, DocsUi::SwaggerUi);
+        assert_eq!(docs.title, "Swagger Petstore");
+        assert_eq!(docs.page_route, "/docs");
+        // The UI loads the document the `openapi` module already serves
+        assert_eq!(docs.spec_route, "/openapi.json");
+        assert!(!docs.original_spec);
+
+        // Without vendored assets the page loads them from the CDN
+        assert!(!docs.embedded);
+        let routes: Vec<_> = docs.assets.iter().map(|a| a.route.as_str()).collect();
+        assert_eq!(
+            routes,
+            vec![
+                "https://unpkg.com/swagger-ui-dist@5/swagger-ui.css",
+                "https://unpkg.com/swagger-ui-dist@5/swagger-ui-bundle.js",
+            ]
+        );
+        assert_eq!(docs.assets[0].content_type, "text/css");
+        assert_eq!(docs.assets[1].source, None);
+
+        let config = DocsConfig {
+            assets_dir: Some("assets/docs".to_string()),
+            ..Default::default()
+        };
+        let docs = DocsTranslator::new().translate(&openapi, &config);
+        assert!(docs.embedded);
+        let routes: Vec<_> = docs.assets.iter().map(|a| a.route.as_str()).collect();
+        assert_eq!(routes, vec!["/docs/swagger-ui.css", "/docs/swagger-ui-bundle.js"]);
+        assert_eq!(docs.assets[1].const_name, "SWAGGER_UI_BUNDLE_JS");
+        assert_eq!(
+            docs.assets[1].source.as_deref(),
+            Some("assets/docs/swagger-ui-bundle.js")
+        );
+        assert_eq!(
+            docs.assets[1].url,
+            "https://unpkg.com/swagger-ui-dist@5/swagger-ui-bundle.js"
+        );
+    }
+
+    #[test]
+    fn test_translate_mount_path() {
+        let openapi = load_petstore();
+        let config = DocsConfig {
+            ui: DocsUi::Redoc,
+            path: "/".to_string(),
+            spec: DocsSpec::Original,
+            assets_dir: Some("vendor/".to_string()),
+        };
+        let docs = DocsTranslator::new().translate(&openapi, &config);
+
+        assert_eq!(docs.page_route, "/");
+        // Mounted at the root, still clear of `OPENAPI_JSON_PATH`
+        assert_eq!(docs.spec_route, "/source.json");
+        assert!(docs.original_spec);
+        assert_eq!(docs.assets[0].route, "/redoc.standalone.js");
+        assert_eq!(docs.assets[0].source.as_deref(), Some("vendor/redoc.standalone.js"));
+    }
+
+    #[test]
+    fn test_docs_module_generation() {
+        let openapi = load_petstore();
+        let config = GeneratorConfig {
+            docs: Some(DocsConfig {
+                ui: DocsUi::Scalar,
+                path: "api-docs/".to_string(),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let files = AxumTemplate::from_openapi_with_config(&openapi, &config).unwrap();
+
+        let (_, docs) = files
+            .iter()
+            .find(|(path, _)| path == "src/docs.rs")
+            .expect("docs.rs not generated");
+        assert!(docs.contains("pub const DOCS_PATH: &str = \"/api-docs\";"));
+        assert!(docs.contains("<script id=\"api-reference\" data-url=\"/openapi.json\"></script>"));
+        // Built as generated: the page loads the UI from the CDN
+        assert!(docs.contains(
+            "<script src=\"https://unpkg.com/@scalar/api-reference@1/dist/browser/standalone.js\">"
+        ));
+        assert!(!docs.contains("include_bytes!"));
+        // No second route for the document
+        assert!(docs.contains("pub const DOCS_SPEC_PATH: &str = crate::openapi::OPENAPI_JSON_PATH;"));
+        assert!(!docs.contains(".route(DOCS_SPEC_PATH"));
+
+        let (_, mod_rs) = files.iter().find(|(path, _)| path == "src/mod.rs").unwrap();
+        assert!(mod_rs.contains("pub mod docs;"));
+        assert!(mod_rs.contains("pub fn app<H>(handlers: H) -> axum::Router\nwhere\n"));
+        assert!(mod_rs
+            .contains("        .merge(crate::pets::handlers::create_router(handlers.clone()))"));
+        assert!(mod_rs.contains("        .merge(crate::openapi::router())"));
+        assert!(mod_rs.contains("        .merge(crate::docs::router())"));
+
+        // Vendored assets are embedded and served next to the page
+        let config = GeneratorConfig {
+            docs: Some(DocsConfig {
+                ui: DocsUi::Scalar,
+                assets_dir: Some("assets/docs".to_string()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let files = AxumTemplate::from_openapi_with_config(&openapi, &config).unwrap();
+        let (_, docs) = files.iter().find(|(path, _)| path == "src/docs.rs").unwrap();
+        assert!(docs.contains("<script src=\"/docs/standalone.js\"></script>"));
+        assert!(docs.contains("\"/assets/docs/standalone.js\""));
+        assert!(docs.contains("//! curl -fsSL --create-dirs -o assets/docs/standalone.js https://"));
+
+        // Without the option there is no docs module
+        let files = AxumTemplate::from_openapi(&openapi).unwrap();
+        assert!(!files.iter().any(|(path, _)| path == "src/docs.rs"));
+    }
+}
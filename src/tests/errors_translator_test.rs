@@ -0,0 +1,246 @@
+#[cfg(test)]
+mod tests {
+    use crate::config::{ErrorStyle, GeneratorConfig};
+    use crate::errors_translator::ErrorsTranslator;
+    use crate::file_utils;
+    use crate::ir::Api;
+    use crate::AxumTemplate;
+    use openapiv3::OpenAPI;
+    use std::path::PathBuf;
+
+    fn errors_spec() -> OpenAPI {
+        serde_json::from_value(serde_json::json!({
+            "openapi": "3.0.0",
+            "info": { "title": "Errors", "version": "1.0.0" },
+            "paths": {
+                "/pets/{petId}": {
+                    "get": {
+                        "operationId": "showPetById",
+                        "responses": {
+                            "200": { "description": "A pet" },
+                            "404": {
+                                "description": "Pet not found",
+                                "content": {
+                                    "application/json": {
+                                        "schema": { "$ref": "#/components/schemas/Error" }
+                                    }
+                                }
+                            },
+                            "4XX": {
+                                "description": "Client error",
+                                "content": {
+                                    "application/json": {
+                                        "schema": { "$ref": "#/components/schemas/Error" }
+                                    }
+                                }
+                            }
+                        }
+                    },
+                    "put": {
+                        "operationId": "updatePet",
+                        "responses": {
+                            "409": { "description": "Pet already exists" },
+                            "default": { "description": "Unexpected error" }
+                        }
+                    }
+                }
+            },
+            "components": {
+                "schemas": {
+                    "Error": {
+                        "type": "object",
+                        "required": ["code", "message"],
+                        "properties": {
+                            "code": { "type": "integer" },
+                            "message": { "type": "string" },
+                            "details": { "type": "string" }
+                        }
+                    }
+                }
+            }
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_translate_error_variants() {
+        let openapi = errors_spec();
+        let api = Api::from_openapi(&openapi);
+        let errors = ErrorsTranslator::new().translate(&api.operations, &api.models);
+
+        let variants: Vec<_> = errors.iter().map(|e| e.variant.as_str()).collect();
+        assert_eq!(variants, vec!["NotFound", "Conflict", "ClientError", "Default"]);
+
+        let not_found = &errors[0];
+        assert_eq!(not_found.status, Some(404));
+        assert_eq!(not_found.status_expr.as_deref(), Some("StatusCode::NOT_FOUND"));
+        let body = not_found.body.as_ref().unwrap();
+        assert_eq!(body.rust_type, "Error");
+        assert_eq!(
+            body.constructor,
+            "Error { code: status.as_u16().into(), message: message.clone(), details: None }"
+        );
+
+        // Declared without a body
+        assert!(errors[1].body.is_none());
+
+        let client_error = &errors[2];
+        assert_eq!(client_error.status, None);
+        assert_eq!(client_error.status_pattern, "400..=499");
+
+        // `default` has no body, so rejections fall back to the last declared one
+        let default = &errors[3];
+        assert_eq!(default.status_pattern, "_");
+        assert_eq!(default.description.as_deref(), Some("Unexpected error"));
+        assert_eq!(default.body.as_ref().unwrap().rust_type, "Error");
+    }
+
+    #[test]
+    fn test_body_without_defaults_is_untyped() {
+        let mut openapi = errors_spec();
+        let schemas = &mut openapi.components.as_mut().unwrap().schemas;
+        let owner = serde_json::json!({
+            "type": "object",
+            "required": ["id"],
+            "properties": { "id": { "type": "integer" } }
+        });
+        schemas.insert("OwnerInfo".to_string(), serde_json::from_value(owner).unwrap());
+        let error = serde_json::json!({
+            "type": "object",
+            "required": ["code", "message", "owner", "tags"],
+            "properties": {
+                "code": { "type": "integer" },
+                "message": { "type": "string" },
+                "owner": { "$ref": "#/components/schemas/OwnerInfo" },
+                "tags": { "type": "array", "items": { "type": "string" } }
+            }
+        });
+        schemas.insert("Error".to_string(), serde_json::from_value(error).unwrap());
+
+        // A model cannot be made up for `owner`, so the body is kept as JSON
+        let api = Api::from_openapi(&openapi);
+        let errors = ErrorsTranslator::new().translate(&api.operations, &api.models);
+        let body = errors[0].body.as_ref().unwrap();
+        assert_eq!(body.rust_type, "serde_json::Value");
+        assert_eq!(
+            body.constructor,
+            "serde_json::json!({ \"code\": status.as_u16(), \"message\": message })"
+        );
+    }
+
+    #[test]
+    fn test_petstore_default_error() {
+        let openapi =
+            file_utils::openapi_from_file(PathBuf::from("src/test_data/petstore.json")).unwrap();
+        let files = AxumTemplate::from_openapi(&openapi).unwrap();
+
+        let (_, errors) = files
+            .iter()
+            .find(|(path, _)| path == "src/errors.rs")
+            .expect("errors.rs not generated");
+        assert!(errors.contains("    Default(StatusCode, Error),"));
+        assert!(errors.contains(
+            "            _ => {
+                Self::Default(
+                    status,
+                    Error {
+                        code: status.as_u16().into(),
+                        message: message.clone(),
+                    },
+                )
+            }"
+        ));
+        assert!(errors.contains("impl From<JsonRejection> for ApiError"));
+
+        let (_, mod_rs) = files.iter().find(|(path, _)| path == "src/mod.rs").unwrap();
+        assert!(mod_rs.contains("pub mod models;"));
+        assert!(mod_rs.contains("pub mod errors;"));
+    }
+
+    #[test]
+    fn test_bodiless_errors_skip_json() {
+        let openapi =
+            file_utils::openapi_from_file(PathBuf::from("src/test_data/documented.json")).unwrap();
+        let files = AxumTemplate::from_openapi(&openapi).unwrap();
+
+        let (_, errors) = files.iter().find(|(path, _)| path == "src/errors.rs").unwrap();
+        assert!(errors.contains("    Default(StatusCode),"));
+        assert!(errors.contains("response::{IntoResponse, Response}"));
+    }
+
+    #[test]
+    fn test_problem_error_style() {
+        let openapi = errors_spec();
+        let api = Api::from_openapi(&openapi);
+        let errors =
+            ErrorsTranslator::with_style(ErrorStyle::Problem).translate(&api.operations, &api.models);
+
+        // Every variant, including those declared without a body, carries a Problem
+        assert!(errors
+            .iter()
+            .all(|e| e.body.as_ref().unwrap().rust_type == "Problem"));
+
+        let config = GeneratorConfig {
+            error_style: ErrorStyle::Problem,
+            ..Default::default()
+        };
+        let files = AxumTemplate::from_openapi_with_config(&openapi, &config).unwrap();
+        let (_, errors) = files
+            .iter()
+            .find(|(path, _)| path == "src/errors.rs")
+            .unwrap();
+        assert!(errors.contains("pub struct Problem {"));
+        assert!(errors.contains("pub const PROBLEM_JSON: &str = \"application/problem+json\";"));
+        assert!(errors.contains("    Conflict(Problem),"));
+        assert!(errors.contains(
+            "404 => Self::NotFound(Problem::new(status).with_detail(message.clone())),"
+        ));
+
+        // Rejections reach the handler's ApiError, so they render as problems
+        // too, and `default` is documented as one
+        let (_, handlers) = files
+            .iter()
+            .find(|(path, _)| path == "src/pets/handlers.rs")
+            .unwrap();
+        assert!(handlers.contains("    path: Result<crate::paths::PetsPetIdPath, PathRejection>,\n"));
+        assert!(handlers.contains("    let path = path?;\n"));
+        assert!(handlers.contains(
+            "            status = \"default\",
+            description = \"Unexpected error\",
+            body = crate::errors::Problem,"
+        ));
+    }
+
+    #[test]
+    fn test_component_responses() {
+        let openapi: OpenAPI = serde_json::from_value(serde_json::json!({
+            "openapi": "3.0.0",
+            "info": { "title": "Errors", "version": "1.0.0" },
+            "paths": {
+                "/items": {
+                    "get": {
+                        "responses": {
+                            "200": { "description": "Items" },
+                            "404": { "$ref": "#/components/responses/NotFound" },
+                            "default": { "$ref": "#/components/responses/Unexpected" }
+                        }
+                    }
+                }
+            },
+            "components": {
+                "responses": {
+                    "NotFound": { "description": "Not found" },
+                    "Unexpected": { "description": "Unexpected error" }
+                }
+            }
+        }))
+        .unwrap();
+
+        let api = Api::from_openapi(&openapi);
+        let errors = ErrorsTranslator::new().translate(&api.operations, &api.models);
+        assert_eq!(errors.len(), 2);
+        assert_eq!(errors[0].variant, "NotFound");
+        assert_eq!(errors[0].description.as_deref(), Some("Not found"));
+        assert_eq!(errors[1].description.as_deref(), Some("Unexpected error"));
+    }
+}
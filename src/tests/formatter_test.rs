@@ -0,0 +1,90 @@
+#[cfg(test)]
+mod tests {
+    use crate::formatter::format_rust;
+    use crate::AxumTemplate;
+    use crate::file_utils;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_format_rust() {
+        let source = "
+// Base API template
+use serde::Serialize;
+
+
+/// Pet model
+#[derive(Serialize)]
+pub struct Pet {
+        pub id: i64,
+
+    pub name: String,
+}
+pub const LIMIT: usize = 100;
+fn limit() -> usize { LIMIT }
+";
+        assert_eq!(
+            format_rust(source).unwrap(),
+            "\
+use serde::Serialize;
+
+/// Pet model
+#[derive(Serialize)]
+pub struct Pet {
+    pub id: i64,
+    pub name: String,
+}
+
+pub const LIMIT: usize = 100;
+
+fn limit() -> usize {
+    LIMIT
+}
+"
+        );
+    }
+
+    #[test]
+    fn test_format_rust_keeps_user_code_markers() {
+        let source = "
+#[utoipa::path(get, path = \"/pets\")]
+   // <user-code name=\"list_pets\">
+// </user-code>
+mod tests {
+        // <user-code name=\"tests\">
+        // </user-code>
+}
+";
+        assert_eq!(
+            format_rust(source).unwrap(),
+            "\
+#[utoipa::path(get, path = \"/pets\")]
+// <user-code name=\"list_pets\">
+// </user-code>
+
+mod tests {
+    // <user-code name=\"tests\">
+    // </user-code>
+}
+"
+        );
+    }
+
+    #[test]
+    fn test_format_rust_reports_line() {
+        let error = format_rust("use serde::Serialize;\n\npub struct Pet {\n    pub type: String,\n}\n")
+            .unwrap_err();
+        assert_eq!(error.span().start().line, 4);
+    }
+
+    #[test]
+    fn test_generated_files_are_formatted() {
+        let openapi =
+            file_utils::openapi_from_file(PathBuf::from("src/test_data/petstore.json")).unwrap();
+        for (path, content) in AxumTemplate::from_openapi(&openapi).unwrap() {
+            if path.ends_with(".rs") {
+                // Formatting is stable, so the output was formatted already
+                assert_eq!(format_rust(&content).unwrap(), content, "{} is not formatted", path);
+            }
+        }
+    }
+}
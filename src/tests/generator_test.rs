@@ -0,0 +1,133 @@
+#[cfg(test)]
+mod tests {
+    use crate::config::{GeneratorConfig, Target};
+    use crate::file_utils;
+    use crate::generator::{FileKind, Generator, GeneratorError};
+    use crate::validate::Origin;
+    use crate::AxumTemplate;
+
+    const PETSTORE: &str = "src/test_data/petstore.json";
+
+    #[test]
+    fn test_generate_from_file() {
+        let project = Generator::builder().spec(PETSTORE).build().unwrap().generate().unwrap();
+
+        let handlers = project.file("src/pets/handlers.rs").unwrap();
+        assert_eq!(
+            handlers.kind,
+            FileKind::Handlers {
+                module: "pets".to_string()
+            }
+        );
+        assert_eq!(handlers.provenance.template, "axum_utoipa.rs.jinja");
+        assert!(handlers
+            .provenance
+            .origins
+            .contains(&Origin::Operation {
+                name: "list_pets".to_string(),
+                method: "get".to_string(),
+                path: "/pets".to_string(),
+            }));
+
+        let models = project.file("src/models.rs").unwrap();
+        assert_eq!(models.kind, FileKind::Models);
+        assert_eq!(
+            models.provenance.origins,
+            vec![
+                Origin::Schema("Pet".to_string()),
+                Origin::Schema("Pets".to_string()),
+                Origin::Schema("Error".to_string()),
+            ]
+        );
+        // Models are plain DTOs; routes get their own typed paths
+        assert!(!models.content.contains("TypedPath"));
+        let paths = project.file("src/paths.rs").unwrap();
+        assert_eq!(paths.kind, FileKind::Paths);
+        assert!(paths.content.contains(r#"#[typed_path("/pets/:pet_id")]"#));
+        assert!(paths.content.contains("pub struct PetsPetIdPath"));
+        assert!(project.file("src/errors.rs").unwrap().provenance.origins.is_empty());
+        assert!(project.diagnostics.is_empty());
+
+        // The same files as the pair API
+        let openapi = file_utils::openapi_from_file(PETSTORE).unwrap();
+        assert_eq!(project.into_pairs(), AxumTemplate::from_openapi(&openapi).unwrap());
+    }
+
+    #[test]
+    fn test_spec_text_is_escaped() {
+        let project = Generator::builder()
+            .spec("src/test_data/documented.json")
+            .build()
+            .unwrap()
+            .generate()
+            .unwrap();
+
+        let handlers = project.file("src/pets/handlers.rs").unwrap();
+        assert!(handlers
+            .content
+            .contains(r#"description = "Pet kind, e.g. \"dog\" or C:\\pets""#));
+        assert!(handlers.content.contains(r#"description = "Every \"good\" pet""#));
+    }
+
+    #[test]
+    fn test_untagged_operations() {
+        let project = Generator::builder()
+            .spec("src/test_data/documented.json")
+            .build()
+            .unwrap()
+            .generate()
+            .unwrap();
+
+        // No tag in the docs, and only the routing functions the module calls
+        let handlers = project.file("src/pets/handlers.rs").unwrap();
+        assert!(!handlers.content.contains("tag ="));
+        assert!(handlers.content.contains("use axum::{Router, routing::{get, post}};"));
+    }
+
+    #[test]
+    fn test_target_overrides_config() {
+        let openapi = file_utils::openapi_from_file(PETSTORE).unwrap();
+        let generator = Generator::builder()
+            .target(Target::Client)
+            .config(GeneratorConfig::default())
+            .spec(&openapi)
+            .build()
+            .unwrap();
+        assert_eq!(generator.config().target, Target::Client);
+
+        let project = generator.generate().unwrap();
+        let kinds: Vec<_> = project.files.iter().map(|file| file.kind.clone()).collect();
+        assert_eq!(kinds, vec![FileKind::Models, FileKind::Client, FileKind::Module]);
+    }
+
+    #[test]
+    fn test_build_errors() {
+        assert!(matches!(
+            Generator::builder().build(),
+            Err(GeneratorError::MissingSpec)
+        ));
+
+        let error = Generator::builder().spec("src/test_data/missing.yaml").build().unwrap_err();
+        assert!(matches!(error, GeneratorError::Spec { .. }));
+        assert!(error.to_string().starts_with("cannot read spec src/test_data/missing.yaml: "));
+        assert_eq!(error.pointer(), None);
+
+        // Crosses threads, e.g. into `anyhow::Error`
+        fn assert_send_sync<E: Send + Sync + 'static>(_: &E) {}
+        assert_send_sync(&error);
+    }
+
+    #[test]
+    fn test_spec_error_pointer() {
+        let dir = tempfile::tempdir().unwrap();
+        let spec = dir.path().join("api.yaml");
+        std::fs::write(
+            &spec,
+            "openapi: 3.0.0\ninfo:\n  title: Pets\n  version: '1'\npaths:\n  /pets:\n    get:\n      responses: []\n",
+        )
+        .unwrap();
+        let error = Generator::builder().spec(spec.as_path()).build().unwrap_err();
+        assert!(error.pointer().unwrap().starts_with("/paths/~1pets/get"));
+        assert!(error.to_string().contains(": at #/paths/~1pets/get"));
+    }
+}
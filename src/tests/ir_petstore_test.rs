@@ -1,6 +1,7 @@
 #[cfg(test)]
 mod tests {
-    use crate::RoutesTranslator;
+    use crate::ir::operations::translate;
+    use crate::ir::{Operation, ParameterLocation};
     use crate::test_utils::TestUtils;
     
     use openapiv3::OpenAPI;
@@ -48,36 +49,35 @@ mod tests {
 
     fn load_test_data(path: &str) -> OpenAPI {
         let json = fs::read_to_string(path)
-            .expect(&format!("Failed to read {}", path));
+            .unwrap_or_else(|_| panic!("Failed to read {}", path));
         serde_json::from_str(&json)
-            .expect(&format!("Failed to parse {}", path))
+            .unwrap_or_else(|_| panic!("Failed to parse {}", path))
     }
 
-    fn verify_route_basics(routes: &Vec<crate::Route>, path: &str, method: &str, handler_name: &str) {
-        let route = routes
+    fn verify_operation_basics(operations: &[Operation], path: &str, method: &str, fn_name: &str) {
+        let operation = operations
             .iter()
-            .find(|r| r.path == path && r.method == method)
-            .unwrap_or_else(|| panic!("Route {} {} not found", method, path));
+            .find(|o| o.path == path && o.http_method == method)
+            .unwrap_or_else(|| panic!("Operation {} {} not found", method, path));
         
-        assert_eq!(route.handler_name, handler_name);
+        assert_eq!(operation.fn_name, fn_name);
     }
 
     #[test]
-    fn test_route_translation() {
-        let translator = RoutesTranslator::new();
-        
-        // Test petstore routes
+    fn test_operation_translation() {
+        // Test petstore operations
         let petstore = load_test_data("./src/test_data/petstore.json");
-        let petstore_routes = translator.translate(&petstore);
-        assert_eq!(petstore_routes.len(), 3);
-        verify_route_basics(&petstore_routes, "/pets", "GET", "handle_get_pets");
-        verify_route_basics(&petstore_routes, "/pets", "POST", "handle_post_pets");
-        verify_route_basics(&petstore_routes, "/pets/{petId}", "GET", "handle_get_pets_petid");
+        let petstore_operations = translate(&petstore);
+        assert_eq!(petstore_operations.len(), 3);
+        verify_operation_basics(&petstore_operations, "/pets", "GET", "list_pets");
+        verify_operation_basics(&petstore_operations, "/pets", "POST", "create_pets");
+        verify_operation_basics(&petstore_operations, "/pets/{petId}", "GET", "show_pet_by_id");
 
-        // Test uspto routes
+        // Test uspto operations
         let uspto = load_test_data("./src/test_data/uspto.json");
-        let uspto_routes = translator.translate(&uspto);
-        assert!(uspto_routes.len() > 0);
+        let uspto_operations = translate(&uspto);
+        assert!(!uspto_operations.is_empty());
+        assert!(uspto_operations.iter().all(|o| !o.fn_name.is_empty() && !o.module.is_empty()));
     }
 
     
@@ -105,51 +105,59 @@ mod tests {
     // }
 
     #[test]
-    fn test_translate_petstore_routes() {
+    fn test_translate_petstore_operations() {
         // Load petstore OpenAPI spec
         let petstore_json = fs::read_to_string("src/test_data/petstore.json")
             .expect("Failed to read petstore.json");
         let openapi: OpenAPI =
             serde_json::from_str(&petstore_json).expect("Failed to parse petstore.json");
 
-        let translator = RoutesTranslator::new();
-        let routes = translator.translate(&openapi);
+        let operations = translate(&openapi);
 
-        // Verify we have all expected routes
-        assert_eq!(routes.len(), 3);
+        // Verify we have all expected operations
+        assert_eq!(operations.len(), 3);
 
-        // Test /pets GET route
-        let list_pets = routes
+        // Test /pets GET operation
+        let list_pets = operations
             .iter()
-            .find(|r| r.path == "/pets" && r.method == "GET")
+            .find(|o| o.path == "/pets" && o.http_method == "GET")
             .unwrap();
-        assert_eq!(list_pets.handler_name, "handle_get_pets");
-        assert_eq!(list_pets.parameters.len(), 1);
-        assert_eq!(list_pets.parameters[0].name, "limit");
-        assert_eq!(list_pets.parameters[0].param_type, "Type(Integer(IntegerType { format: Item(Int32), multiple_of: None, exclusive_minimum: false, exclusive_maximum: false, minimum: None, maximum: None, enumeration: [] }))");
-        assert!(!list_pets.parameters[0].required);
-        assert_eq!(list_pets.responses.len(), 1);
-
-        // Test /pets POST route
-        let create_pets = routes
+        assert_eq!(list_pets.fn_name, "list_pets");
+        assert_eq!(list_pets.module, "pets");
+        assert_eq!(list_pets.params.len(), 1);
+        assert_eq!(list_pets.params[0].name, "limit");
+        assert_eq!(list_pets.params[0].rust_type, "i32");
+        assert_eq!(list_pets.params[0].location, ParameterLocation::Query);
+        assert!(!list_pets.params[0].required);
+        // 200 and `default`, which comes last
+        assert_eq!(list_pets.responses.len(), 2);
+        let default = &list_pets.responses[1];
+        assert_eq!(default.status, None);
+        assert_eq!(default.description.as_deref(), Some("unexpected error"));
+        assert_eq!(default.rust_type.as_deref(), Some("Error"));
+        assert!(default.is_error());
+
+        // Test /pets POST operation
+        let create_pets = operations
             .iter()
-            .find(|r| r.path == "/pets" && r.method == "POST")
+            .find(|o| o.path == "/pets" && o.http_method == "POST")
             .unwrap();
-        assert_eq!(create_pets.handler_name, "handle_post_pets");
-        assert_eq!(create_pets.parameters.len(), 0);
-        assert_eq!(create_pets.responses.len(), 1);
+        assert_eq!(create_pets.fn_name, "create_pets");
+        assert_eq!(create_pets.params.len(), 0);
+        assert_eq!(create_pets.responses.len(), 2);
 
-        // Test /pets/{petId} GET route
-        let show_pet = routes
+        // Test /pets/{petId} GET operation
+        let show_pet = operations
             .iter()
-            .find(|r| r.path == "/pets/{petId}" && r.method == "GET")
+            .find(|o| o.path == "/pets/{petId}" && o.http_method == "GET")
             .unwrap();
-        assert_eq!(show_pet.handler_name, "handle_get_pets_petid");
-        assert_eq!(show_pet.parameters.len(), 1);
-        assert_eq!(show_pet.parameters[0].name, "petId");
-        assert!(show_pet.parameters[0].param_type.contains("Type(String"));
-        assert!(show_pet.parameters[0].required);
-        assert_eq!(show_pet.responses.len(), 1);
+        assert_eq!(show_pet.fn_name, "show_pet_by_id");
+        assert_eq!(show_pet.params.len(), 1);
+        assert_eq!(show_pet.params[0].name, "petId");
+        assert_eq!(show_pet.params[0].rust_name, "pet_id");
+        assert_eq!(show_pet.params[0].rust_type, "String");
+        assert!(show_pet.params[0].required);
+        assert_eq!(show_pet.responses.len(), 2);
     }
 
     fn init() {
@@ -0,0 +1,652 @@
+#[cfg(test)]
+mod tests {
+    use crate::ir::operations::{translate, translate_with_diagnostics};
+    use crate::ir::resolve::Resolver;
+    use crate::ir::types::TypeMapper;
+    use crate::ir::{Api, ParameterLocation};
+    use openapiv3::{
+        Components, OpenAPI, Operation, Parameter, ParameterData, ParameterSchemaOrContent, PathItem,
+        ReferenceOr, Response, Schema, SchemaKind, StatusCode, Type,
+    };
+
+    fn create_test_openapi() -> OpenAPI {
+        OpenAPI {
+            openapi: "3.0.0".to_string(),
+            paths: Default::default(),
+            components: Some(Components {
+                schemas: Default::default(),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_translate_route_with_tags() {
+        let mut openapi = create_test_openapi();
+        openapi.paths.paths.insert(
+            "/test".to_string(),
+            ReferenceOr::Item(PathItem {
+                get: Some(Operation {
+                    operation_id: Some("testOperation".to_string()),
+                    parameters: vec![],
+                    responses: Default::default(),
+                    tags: vec!["pets".to_string()],
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+        );
+
+        let operations = translate(&openapi);
+
+        assert_eq!(operations.len(), 1);
+        let operation = &operations[0];
+        assert_eq!(operation.tag, "pets");
+        assert_eq!(operation.folder, "pets");
+    }
+
+    #[test]
+    fn test_translate_basic_route() {
+        let mut openapi = create_test_openapi();
+        openapi.paths.paths.insert(
+            "/test".to_string(),
+            ReferenceOr::Item(PathItem {
+                get: Some(Operation {
+                    operation_id: Some("testOperation".to_string()),
+                    parameters: vec![],
+                    responses: Default::default(),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+        );
+
+        let operations = translate(&openapi);
+
+        assert_eq!(operations.len(), 1);
+        let operation = &operations[0];
+        assert_eq!(operation.path, "/test");
+        assert_eq!(operation.http_method, "GET");
+        assert_eq!(operation.fn_name, "test_operation");
+        assert_eq!(operation.module, "test");
+    }
+
+    #[test]
+    fn test_translate_route_with_parameters() {
+        let mut openapi = create_test_openapi();
+        openapi.paths.paths.insert(
+            "/test/{id}".to_string(),
+            ReferenceOr::Item(PathItem {
+                get: Some(Operation {
+                    operation_id: Some("testOperation".to_string()),
+                    parameters: vec![ReferenceOr::Item(Parameter::Query {
+                        parameter_data: ParameterData {
+                            name: "id".to_string(),
+                            description: None,
+                            required: true,
+                            deprecated: Some(false),
+                            format: ParameterSchemaOrContent::Schema(ReferenceOr::Item(Schema {
+                                schema_kind: SchemaKind::Type(Type::String(Default::default())),
+                                schema_data: Default::default(),
+                            })),
+                            example: None,
+                            examples: Default::default(),
+                            explode: Some(false),
+                            extensions: Default::default(),
+                        },
+                        allow_empty_value: Some(false),
+                        style: openapiv3::QueryStyle::Form,
+                        allow_reserved: false,
+                    })],
+                    responses: Default::default(),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+        );
+
+        let operations = translate(&openapi);
+
+        assert_eq!(operations.len(), 1);
+        let operation = &operations[0];
+        assert_eq!(operation.params.len(), 1);
+        let param = &operation.params[0];
+        assert_eq!(param.name, "id");
+        assert_eq!(param.rust_type, "String");
+        assert_eq!(param.location, ParameterLocation::Query);
+        assert!(param.required);
+    }
+
+    #[test]
+    fn test_translate_route_with_responses() {
+        let mut openapi = create_test_openapi();
+        openapi.paths.paths.insert(
+            "/test".to_string(),
+            ReferenceOr::Item(PathItem {
+                get: Some(Operation {
+                    operation_id: Some("testOperation".to_string()),
+                    parameters: vec![],
+                    responses: openapiv3::Responses {
+                        responses: vec![
+                            (
+                                StatusCode::Code(200),
+                                ReferenceOr::Item(Response {
+                                    description: "Success".to_string(),
+                                    content: Default::default(),
+                                    ..Default::default()
+                                }),
+                            ),
+                            (
+                                StatusCode::Code(404),
+                                ReferenceOr::Item(Response {
+                                    description: "Not Found".to_string(),
+                                    content: Default::default(),
+                                    ..Default::default()
+                                }),
+                            ),
+                        ]
+                        .into_iter()
+                        .collect(),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+        );
+
+        let operations = translate(&openapi);
+
+        assert_eq!(operations.len(), 1);
+        let operation = &operations[0];
+        assert_eq!(operation.responses.len(), 2);
+
+        let success_response = &operation.responses[0];
+        assert_eq!(success_response.status, Some(200));
+        assert_eq!(success_response.description.as_deref(), Some("Success"));
+
+        let not_found_response = &operation.responses[1];
+        assert_eq!(not_found_response.status, Some(404));
+        assert_eq!(not_found_response.description.as_deref(), Some("Not Found"));
+    }
+
+    #[test]
+    fn test_translate_route_with_reserved_keyword_parameter() {
+        let mut openapi = create_test_openapi();
+        openapi.paths.paths.insert(
+            "/test/{type}".to_string(),
+            ReferenceOr::Item(PathItem {
+                get: Some(Operation {
+                    operation_id: Some("testOperation".to_string()),
+                    parameters: vec![ReferenceOr::Item(Parameter::Query {
+                        parameter_data: ParameterData {
+                            name: "type".to_string(),
+                            description: None,
+                            required: true,
+                            deprecated: Some(false),
+                            format: ParameterSchemaOrContent::Schema(ReferenceOr::Item(Schema {
+                                schema_kind: SchemaKind::Type(Type::String(Default::default())),
+                                schema_data: Default::default(),
+                            })),
+                            example: None,
+                            examples: Default::default(),
+                            explode: Some(false),
+                            extensions: Default::default(),
+                        },
+                        allow_empty_value: Some(false),
+                        style: openapiv3::QueryStyle::Form,
+                        allow_reserved: false,
+                    })],
+                    responses: Default::default(),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+        );
+
+        let operations = translate(&openapi);
+
+        assert_eq!(operations.len(), 1);
+        let operation = &operations[0];
+        assert_eq!(operation.params.len(), 1);
+        let param = &operation.params[0];
+        assert_eq!(param.name, "type");
+        assert_eq!(param.rust_name, "r#type"); // Expect escaped keyword
+        assert_eq!(param.rust_type, "String");
+        assert!(param.required);
+    }
+
+    #[test]
+    fn test_type_mapping_is_shared() {
+        let openapi: OpenAPI = serde_json::from_value(serde_json::json!({
+            "openapi": "3.0.0",
+            "info": { "title": "Types", "version": "1.0.0" },
+            "paths": {
+                "/items/{id}": {
+                    "get": {
+                        "operationId": "getItem",
+                        "parameters": [
+                            { "name": "id", "in": "path", "required": true, "schema": { "type": "integer" } },
+                            { "name": "page", "in": "query", "schema": { "type": "integer", "format": "int32" } },
+                            { "name": "ratio", "in": "query", "schema": { "type": "number", "format": "float" } },
+                            { "name": "filter", "in": "query", "schema": { "type": "object", "title": "Filter" } }
+                        ],
+                        "responses": {
+                            "200": {
+                                "description": "The item",
+                                "content": { "application/json": { "schema": { "$ref": "#/components/schemas/item-list" } } }
+                            }
+                        }
+                    }
+                }
+            },
+            "components": {
+                "schemas": {
+                    "item-list": {
+                        "type": "object",
+                        "required": ["id"],
+                        "properties": {
+                            "id": { "type": "integer" },
+                            "page": { "type": "integer", "format": "int32" },
+                            "ratio": { "type": "number", "format": "float" },
+                            "filter": { "type": "object", "title": "Filter" },
+                            "tags": { "type": "array", "items": { "type": "string" } }
+                        }
+                    }
+                }
+            }
+        }))
+        .unwrap();
+
+        let api = Api::from_openapi(&openapi);
+        let operation = &api.operations[0];
+        let param_types: Vec<&str> = operation.params.iter().map(|p| p.rust_type.as_str()).collect();
+        assert_eq!(param_types, ["i64", "i32", "f32", "serde_json::Value"]);
+        assert_eq!(operation.responses[0].rust_type.as_deref(), Some("ItemList"));
+
+        // Model fields map the same schemas to the same types
+        let model = &api.models[0];
+        assert_eq!(model.name, "ItemList");
+        assert_eq!(model.spec_name, "item-list");
+        let field_types: Vec<&str> = model.fields.iter().map(|f| f.rust_type.as_str()).collect();
+        assert_eq!(
+            field_types,
+            [
+                "i64",
+                "Option<i32>",
+                "Option<f32>",
+                "Option<serde_json::Value>",
+                "Option<Vec<String>>"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_operations_grouped_by_module() {
+        let openapi: OpenAPI = serde_json::from_value(serde_json::json!({
+            "openapi": "3.0.0",
+            "info": { "title": "Modules", "version": "1.0.0" },
+            "paths": {
+                "/users/{id}": { "get": { "operationId": "getUser", "responses": {} } },
+                "/": { "get": { "operationId": "index", "responses": {} } },
+                "/users": { "get": { "operationId": "listUsers", "responses": {} } }
+            }
+        }))
+        .unwrap();
+
+        let api = Api::from_openapi(&openapi);
+        assert_eq!(api.modules(), ["users", "root"]);
+        let users: Vec<String> = api
+            .module_operations("users")
+            .into_iter()
+            .map(|operation| operation.fn_name)
+            .collect();
+        assert_eq!(users, ["get_user", "list_users"]);
+    }
+
+    #[test]
+    fn test_translate_all_methods() {
+        let openapi: OpenAPI = serde_json::from_value(serde_json::json!({
+            "openapi": "3.0.0",
+            "info": { "title": "Methods", "version": "1.0.0" },
+            "paths": {
+                "/items": {
+                    "trace": { "operationId": "traceItems", "responses": {} },
+                    "options": { "operationId": "itemOptions", "responses": {} },
+                    "head": { "operationId": "headItems", "responses": {} },
+                    "get": { "operationId": "listItems", "responses": {} }
+                }
+            }
+        }))
+        .unwrap();
+
+        let methods: Vec<String> = translate(&openapi)
+            .into_iter()
+            .map(|operation| operation.http_method)
+            .collect();
+        assert_eq!(methods, ["GET", "HEAD", "OPTIONS", "TRACE"]);
+    }
+
+    #[test]
+    fn test_path_item_parameters_are_inherited() {
+        let openapi: OpenAPI = serde_json::from_value(serde_json::json!({
+            "openapi": "3.0.0",
+            "info": { "title": "Params", "version": "1.0.0" },
+            "paths": {
+                "/items/{id}": {
+                    "parameters": [
+                        { "name": "id", "in": "path", "required": true, "schema": { "type": "string" } },
+                        { "name": "verbose", "in": "query", "schema": { "type": "boolean" } }
+                    ],
+                    "get": { "operationId": "getItem", "responses": {} },
+                    "delete": {
+                        "operationId": "deleteItem",
+                        "parameters": [
+                            {
+                                "name": "id", "in": "path", "required": true,
+                                "description": "Overridden", "schema": { "type": "integer" }
+                            },
+                            { "name": "id", "in": "header", "schema": { "type": "string" } }
+                        ],
+                        "responses": {}
+                    }
+                }
+            }
+        }))
+        .unwrap();
+
+        let (operations, diagnostics) = translate_with_diagnostics(
+            &openapi,
+            &TypeMapper::default(),
+            &Resolver::new(&openapi),
+        );
+        assert!(diagnostics.is_empty());
+
+        let get_item = &operations[0];
+        let params: Vec<_> = get_item.params.iter().map(|p| (p.name.as_str(), p.rust_type.as_str())).collect();
+        assert_eq!(params, [("id", "String"), ("verbose", "bool")]);
+
+        // Same name and location overrides in place; another location is a new parameter
+        let delete_item = &operations[1];
+        assert_eq!(delete_item.params.len(), 3);
+        assert_eq!(delete_item.params[0].rust_type, "i64");
+        assert_eq!(delete_item.params[0].description.as_deref(), Some("Overridden"));
+        assert_eq!(delete_item.params[2].location, ParameterLocation::Header);
+    }
+
+    #[test]
+    fn test_path_item_references() {
+        let openapi: OpenAPI = serde_json::from_value(serde_json::json!({
+            "openapi": "3.0.0",
+            "info": { "title": "Refs", "version": "1.0.0" },
+            "paths": {
+                "/items": {
+                    "get": { "operationId": "listItems", "responses": {} }
+                },
+                "/v2/items": { "$ref": "#/paths/~1items" },
+                "/loop": { "$ref": "#/paths/~1loop" },
+                "/external": { "$ref": "./items.yaml" }
+            }
+        }))
+        .unwrap();
+
+        let (operations, diagnostics) = translate_with_diagnostics(
+            &openapi,
+            &TypeMapper::default(),
+            &Resolver::new(&openapi),
+        );
+        let paths: Vec<_> = operations.iter().map(|o| o.path.as_str()).collect();
+        assert_eq!(paths, ["/items", "/v2/items"]);
+        assert_eq!(operations[1].module, "v2");
+
+        // The referenced operation keeps its id, so the copy is renamed
+        assert_eq!(operations[1].fn_name, "list_items_2");
+
+        let pointers: Vec<_> = diagnostics.iter().map(|d| d.pointer.as_str()).collect();
+        assert_eq!(pointers, ["/paths/~1loop", "/paths/~1external", "/paths/~1v2~1items/get"]);
+        assert_eq!(
+            diagnostics[1].to_string(),
+            "at #/paths/~1external: external reference `./items.yaml` is not supported"
+        );
+    }
+
+    #[test]
+    fn test_repeated_operation_names_are_renamed() {
+        let openapi: OpenAPI = serde_json::from_value(serde_json::json!({
+            "openapi": "3.0.0",
+            "info": { "title": "Repeated", "version": "1.0.0" },
+            "paths": {
+                "/validators/{id}": {
+                    "get": { "operationId": "ValidatorsController_validator", "responses": {} }
+                },
+                "/validators/registered/{id}": {
+                    "get": { "operationId": "ValidatorsController_validator", "responses": {} },
+                    "delete": { "operationId": "validatorsControllerValidator", "responses": {} }
+                }
+            }
+        }))
+        .unwrap();
+
+        let (operations, diagnostics) = translate_with_diagnostics(
+            &openapi,
+            &TypeMapper::default(),
+            &Resolver::new(&openapi),
+        );
+        let names: Vec<_> = operations.iter().map(|o| o.fn_name.as_str()).collect();
+        assert_eq!(
+            names,
+            [
+                "validators_controller_validator",
+                "validators_controller_validator_2",
+                "validators_controller_validator_3",
+            ]
+        );
+        let messages: Vec<_> = diagnostics
+            .iter()
+            .filter(|d| d.message.contains("already taken"))
+            .map(|d| d.to_string())
+            .collect();
+        assert_eq!(
+            messages,
+            [
+                "at #/paths/~1validators~1registered~1{id}/get: operation name \
+                 `validators_controller_validator` is already taken, renamed to \
+                 `validators_controller_validator_2`",
+                "at #/paths/~1validators~1registered~1{id}/delete: operation name \
+                 `validators_controller_validator` is already taken, renamed to \
+                 `validators_controller_validator_3`",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_undeclared_path_parameter_diagnostic() {
+        let openapi: OpenAPI = serde_json::from_value(serde_json::json!({
+            "openapi": "3.0.0",
+            "info": { "title": "Undeclared", "version": "1.0.0" },
+            "paths": {
+                "/items/{id}/tags/{tag}": {
+                    "get": {
+                        "operationId": "getTag",
+                        "parameters": [
+                            { "name": "tag", "in": "path", "required": true, "schema": { "type": "string" } },
+                            { "name": "id", "in": "query", "schema": { "type": "string" } }
+                        ],
+                        "responses": {}
+                    }
+                }
+            }
+        }))
+        .unwrap();
+
+        let api = Api::from_openapi(&openapi);
+        assert_eq!(api.operations.len(), 1);
+        assert_eq!(api.diagnostics.len(), 1);
+        assert_eq!(
+            api.diagnostics[0].to_string(),
+            "at #/paths/~1items~1{id}~1tags~1{tag}/get: path parameter `{id}` is not declared"
+        );
+    }
+
+    fn components_spec() -> OpenAPI {
+        serde_json::from_value(serde_json::json!({
+            "openapi": "3.0.0",
+            "info": { "title": "Components", "version": "1.0.0" },
+            "paths": {
+                "/items": {
+                    "parameters": [{ "$ref": "#/components/parameters/PageSize" }],
+                    "post": {
+                        "operationId": "createItem",
+                        "parameters": [
+                            { "$ref": "#/components/parameters/Limit" },
+                            { "$ref": "#/components/parameters/Loop" }
+                        ],
+                        "requestBody": { "$ref": "#/components/requestBodies/NewItem" },
+                        "responses": {
+                            "201": { "$ref": "#/components/responses/Created" },
+                            "404": { "$ref": "#/components/responses/NotFound" },
+                            "500": { "$ref": "#/components/responses/Missing" }
+                        }
+                    }
+                }
+            },
+            "components": {
+                "parameters": {
+                    "PageSize": {
+                        "name": "pageSize", "in": "query",
+                        "schema": { "type": "integer", "format": "int32" }
+                    },
+                    // A chain of references resolves to its end
+                    "Limit": { "$ref": "#/components/parameters/PageLimit" },
+                    "PageLimit": { "name": "limit", "in": "query", "schema": { "type": "integer" } },
+                    "Loop": { "$ref": "#/components/parameters/LoopBack" },
+                    "LoopBack": { "$ref": "#/components/parameters/Loop" }
+                },
+                "requestBodies": {
+                    "NewItem": {
+                        "description": "The item to create",
+                        "content": { "application/json": { "schema": { "$ref": "#/components/schemas/Item" } } }
+                    }
+                },
+                "responses": {
+                    "Created": { "description": "Created" },
+                    "NotFound": {
+                        "description": "Not found",
+                        "content": { "application/json": { "schema": { "$ref": "#/components/schemas/Error" } } }
+                    }
+                },
+                "schemas": {
+                    "Item": { "type": "object" },
+                    "Error": { "type": "object" }
+                }
+            }
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_component_references_are_resolved() {
+        let spec = components_spec();
+        let (operations, diagnostics) =
+            translate_with_diagnostics(&spec, &TypeMapper::default(), &Resolver::new(&spec));
+        let operation = &operations[0];
+
+        let params: Vec<_> = operation.params.iter().map(|p| (p.name.as_str(), p.rust_type.as_str())).collect();
+        assert_eq!(params, [("pageSize", "i32"), ("limit", "i64")]);
+
+        let body = operation.request_body.as_ref().unwrap();
+        assert_eq!(body.rust_type, "Item");
+        assert_eq!(body.description.as_deref(), Some("The item to create"));
+
+        let responses: Vec<_> = operation
+            .responses
+            .iter()
+            .map(|r| (r.status, r.description.as_deref(), r.rust_type.as_deref()))
+            .collect();
+        assert_eq!(
+            responses,
+            [(Some(201), Some("Created"), None), (Some(404), Some("Not found"), Some("Error"))]
+        );
+
+        // Cycles and dangling references are reported, not followed forever
+        let messages: Vec<_> = diagnostics.iter().map(|d| d.to_string()).collect();
+        assert_eq!(
+            messages,
+            [
+                "at #/paths/~1items/post/parameters/1: reference `#/components/parameters/Loop` refers to itself",
+                "at #/paths/~1items/post/responses/500: reference `#/components/responses/Missing` does not resolve",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_dangling_schema_references_are_untyped() {
+        let openapi: OpenAPI = serde_json::from_value(serde_json::json!({
+            "openapi": "3.0.0",
+            "info": { "title": "Dangling", "version": "1.0.0" },
+            "paths": {
+                "/operators": {
+                    "post": {
+                        "operationId": "createOperator",
+                        "requestBody": {
+                            "content": {
+                                "application/json": {
+                                    "schema": { "$ref": "#/components/schemas/OperatorMetadataDto" }
+                                }
+                            }
+                        },
+                        "responses": {}
+                    }
+                }
+            },
+            "components": {
+                "schemas": {
+                    "Operator": {
+                        "type": "object",
+                        "properties": {
+                            "metadata": { "$ref": "#/components/schemas/OperatorMetadataDto" }
+                        }
+                    }
+                }
+            }
+        }))
+        .unwrap();
+
+        let api = Api::from_openapi(&openapi);
+        let body = api.operations[0].request_body.as_ref().unwrap();
+        assert_eq!(body.rust_type, "serde_json::Value");
+        let operator = &api.models[0];
+        assert_eq!(operator.fields[0].rust_type, "Option<serde_json::Value>");
+
+        // Each place the reference appears is reported
+        let messages: Vec<_> = api.diagnostics.iter().map(|d| d.to_string()).collect();
+        assert_eq!(
+            messages,
+            [
+                "at #/paths/~1operators/post/requestBody/content/application~1json/schema: \
+                 reference `#/components/schemas/OperatorMetadataDto` does not resolve",
+                "at #/components/schemas/Operator/properties/metadata: reference \
+                 `#/components/schemas/OperatorMetadataDto` does not resolve",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_documentation_and_deprecation() {
+        let openapi =
+            crate::file_utils::openapi_from_file("src/test_data/documented.json").unwrap();
+        let operations = translate(&openapi);
+
+        let add = operations.iter().find(|op| op.fn_name == "add_pet").unwrap();
+        assert_eq!(
+            add.doc_comment.as_deref(),
+            Some("/// Add a pet\n///\n/// Adds a pet to the store.\n/// The id is assigned by the server.")
+        );
+        assert!(!add.deprecated);
+
+        let legacy = operations.iter().find(|op| op.fn_name == "list_pets_legacy").unwrap();
+        assert_eq!(legacy.doc_comment.as_deref(), Some("/// List pets"));
+        assert!(legacy.deprecated);
+    }
+}
@@ -0,0 +1,38 @@
+#[cfg(test)]
+mod tests {
+    use crate::ir::keywords::{escape_rust_keyword, field_name, unraw};
+    use crate::ir::types::type_name;
+
+    #[test]
+    fn test_escape_rust_keyword() {
+        assert_eq!(escape_rust_keyword("type"), "r#type");
+        assert_eq!(escape_rust_keyword("yield"), "r#yield");
+        // Raw identifiers cannot spell these
+        assert_eq!(escape_rust_keyword("self"), "self_");
+        assert_eq!(escape_rust_keyword("Self"), "Self_");
+        assert_eq!(escape_rust_keyword("crate"), "crate_");
+        assert_eq!(escape_rust_keyword("pet"), "pet");
+    }
+
+    #[test]
+    fn test_field_name() {
+        assert_eq!(field_name("operatorName"), "operator_name");
+        assert_eq!(field_name("eth1NodeClient"), "eth1_node_client");
+        assert_eq!(field_name("x-request-id"), "x_request_id");
+        assert_eq!(field_name("@type"), "r#type");
+        assert_eq!(field_name("self"), "self_");
+        assert_eq!(field_name("1st"), "_1st");
+        assert_eq!(field_name("$"), "field");
+        assert_eq!(unraw(&field_name("type")), "type");
+    }
+
+    #[test]
+    fn test_type_name() {
+        assert_eq!(type_name("pet-list"), "PetList");
+        assert_eq!(type_name("dataSetList"), "DataSetList");
+        assert_eq!(type_name("HTTPError"), "HTTPError");
+        assert_eq!(type_name("pet.v1"), "PetV1");
+        assert_eq!(type_name("2fa_status"), "_2faStatus");
+        assert_eq!(type_name("self"), "Self_");
+    }
+}
@@ -0,0 +1,333 @@
+#[cfg(test)]
+mod tests {
+    use crate::config::{GeneratorConfig, MapType};
+    use crate::file_utils;
+    use crate::ir::models::{translate, Model};
+    use crate::ir::types::{rename_types, TypeMapper};
+    use crate::ir::Api;
+    use crate::AxumTemplate;
+    use openapiv3::OpenAPI;
+    use std::collections::BTreeMap;
+
+    fn field_type<'a>(models: &'a [Model], model: &str, field: &str) -> &'a str {
+        let model = models.iter().find(|m| m.name == model).unwrap();
+        &model.fields.iter().find(|f| f.name == field).unwrap().rust_type
+    }
+
+    #[test]
+    fn test_recursive_models_are_boxed() {
+        let openapi = file_utils::openapi_from_file("src/test_data/recursive.json").unwrap();
+        let models = translate(&openapi, &TypeMapper::default());
+
+        // Self reference
+        assert_eq!(field_type(&models, "Node", "parent"), "Option<Box<Node>>");
+        assert_eq!(field_type(&models, "Node", "children"), "Option<Vec<Node>>");
+
+        // Mutual recursion boxes every edge of the cycle, and only those
+        assert_eq!(field_type(&models, "Thread", "root"), "Box<Comment>");
+        assert_eq!(field_type(&models, "Comment", "thread"), "Option<Box<Thread>>");
+        assert_eq!(field_type(&models, "Comment", "replies"), "Option<Vec<Comment>>");
+        assert_eq!(field_type(&models, "Comment", "author"), "Author");
+    }
+
+    #[test]
+    fn test_reference_chains() {
+        let openapi = file_utils::openapi_from_file("src/test_data/aliases.json").unwrap();
+        let types = TypeMapper::for_spec(&openapi, &GeneratorConfig::default());
+        let models = translate(&openapi, &types);
+
+        // Aliases name the model they stand for, and cycles through them
+        // are still found
+        assert_eq!(field_type(&models, "Category", "parent"), "Option<Box<Category>>");
+        assert!(!models.iter().any(|model| model.name == "ParentCategory"));
+        // Cyclic aliases do not hang the translation and stand for nothing
+        assert_eq!(field_type(&models, "Game", "ball"), "Option<serde_json::Value>");
+
+        let api = Api::from_openapi(&openapi);
+        let response = &api.operations[0].responses[0];
+        assert_eq!(response.rust_type.as_deref(), Some("Category"));
+    }
+
+    fn maps_spec() -> openapiv3::OpenAPI {
+        file_utils::openapi_from_file("src/test_data/maps.json").unwrap()
+    }
+
+    #[test]
+    fn test_additional_properties() {
+        let models = translate(&maps_spec(), &TypeMapper::default());
+
+        assert_eq!(
+            field_type(&models, "Labels", "counts"),
+            "Option<std::collections::HashMap<String, i32>>"
+        );
+        assert_eq!(
+            field_type(&models, "Labels", "anything"),
+            "Option<std::collections::HashMap<String, serde_json::Value>>"
+        );
+        assert_eq!(field_type(&models, "Labels", "opaque"), "Option<serde_json::Value>");
+
+        let tagged = models.iter().find(|m| m.name == "Tagged").unwrap();
+        let extra = tagged.fields.last().unwrap();
+        assert_eq!(extra.name, "extra");
+        assert_eq!(extra.rust_type, "std::collections::HashMap<String, Labels>");
+        assert_eq!(extra.attributes, vec!["serde(flatten)"]);
+        assert!(tagged.attributes.is_empty());
+
+        let closed = models.iter().find(|m| m.name == "Closed").unwrap();
+        assert_eq!(closed.fields.len(), 1);
+        assert_eq!(closed.attributes, vec!["serde(deny_unknown_fields)"]);
+    }
+
+    #[test]
+    fn test_btree_map_type() {
+        let types = TypeMapper {
+            map_type: MapType::BTreeMap,
+            ..TypeMapper::default()
+        };
+        let models = translate(&maps_spec(), &types);
+
+        assert_eq!(
+            field_type(&models, "Labels", "counts"),
+            "Option<std::collections::BTreeMap<String, i32>>"
+        );
+        assert_eq!(
+            field_type(&models, "Tagged", "extra"),
+            "std::collections::BTreeMap<String, Labels>"
+        );
+    }
+
+    #[test]
+    fn test_field_names_keep_wire_names() {
+        let openapi: openapiv3::OpenAPI = serde_json::from_value(serde_json::json!({
+            "openapi": "3.0.0",
+            "info": { "title": "Names", "version": "1.0.0" },
+            "paths": {},
+            "components": {
+                "schemas": {
+                    "dataSetList": {
+                        "type": "object",
+                        "properties": {
+                            "operatorName": { "type": "string" },
+                            "type": { "type": "string" },
+                            "self": { "type": "string" },
+                            "x-request-id": { "type": "string" },
+                            "x_request_id": { "type": "string" },
+                            "2fa": { "type": "boolean" },
+                            "id": { "type": "string" }
+                        }
+                    }
+                }
+            }
+        }))
+        .unwrap();
+
+        let models = translate(&openapi, &TypeMapper::default());
+        let model = &models[0];
+        assert_eq!(model.name, "DataSetList");
+        let fields: Vec<(&str, Vec<String>)> = model
+            .fields
+            .iter()
+            .map(|field| (field.name.as_str(), field.attributes.clone()))
+            .collect();
+        assert_eq!(
+            fields,
+            vec![
+                ("operator_name", vec![r#"serde(rename = "operatorName")"#.to_string()]),
+                ("r#type", vec![]),
+                ("self_", vec![r#"serde(rename = "self")"#.to_string()]),
+                ("x_request_id", vec![r#"serde(rename = "x-request-id")"#.to_string()]),
+                ("x_request_id_2", vec![r#"serde(rename = "x_request_id")"#.to_string()]),
+                ("_2fa", vec![r#"serde(rename = "2fa")"#.to_string()]),
+                ("id", vec![]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_field_documentation() {
+        let openapi = file_utils::openapi_from_file("src/test_data/documented.json").unwrap();
+        let models = translate(&openapi, &TypeMapper::default());
+        let pet = &models[0];
+        let field = |name: &str| pet.fields.iter().find(|f| f.name == name).unwrap();
+
+        assert_eq!(
+            pet.description.as_deref(),
+            Some("A pet for sale.\nPets are listed until sold.")
+        );
+        assert_eq!(pet.attributes, vec![r#"schema(example = json!({"id":1,"name":"Rex"}))"#]);
+
+        assert_eq!(field("name").description.as_deref(), Some("The pet's name"));
+        assert_eq!(field("name").attributes, vec![r#"schema(example = json!("Rex"))"#]);
+        assert!(field("id").read_only);
+        assert_eq!(field("id").attributes, vec!["schema(read_only)"]);
+        assert!(field("password").write_only);
+        assert_eq!(field("nickname").attributes, vec!["deprecated"]);
+
+        // Defaults replace `None`, through an associated function
+        let status = field("status");
+        assert_eq!(status.rust_type, "String");
+        assert_eq!(status.attributes, vec![r#"schema(default = json!("available"))"#]);
+        assert_eq!(status.default.as_ref().unwrap().function, "default_status");
+        let default = |name: &str| field(name).default.as_ref().unwrap().expression.clone();
+        assert_eq!(default("status"), r#""available".to_string()"#);
+        assert_eq!(default("legs"), "4i32");
+        assert_eq!(default("weight"), "1.5f64");
+        assert_eq!(default("vaccinated"), "false");
+        assert_eq!(
+            default("tags"),
+            r#"serde_json::from_value(serde_json::json!(["new"])).unwrap_or_default()"#
+        );
+        // A default of the wrong type is only documented
+        assert!(field("age").default.is_none());
+        assert_eq!(field("age").rust_type, "Option<i64>");
+    }
+
+    #[test]
+    fn test_read_write_only_variants() {
+        let openapi = file_utils::openapi_from_file("src/test_data/documented.json").unwrap();
+        let api = Api::from_openapi(&openapi);
+
+        let names: Vec<&str> = api.models.iter().map(|m| m.name.as_str()).collect();
+        assert_eq!(names, vec!["Pet", "PetCreate"]);
+        let fields = |model: &Model| -> Vec<String> {
+            model.fields.iter().map(|f| f.name.clone()).collect()
+        };
+        // Responses never carry `password`, requests never carry `id`
+        assert!(!fields(&api.models[0]).contains(&"password".to_string()));
+        assert!(fields(&api.models[0]).contains(&"id".to_string()));
+        assert!(fields(&api.models[1]).contains(&"password".to_string()));
+        assert!(!fields(&api.models[1]).contains(&"id".to_string()));
+
+        let add = api.operations.iter().find(|op| op.fn_name == "add_pet").unwrap();
+        assert_eq!(add.request_body.as_ref().unwrap().rust_type, "PetCreate");
+        assert_eq!(add.responses[0].rust_type.as_deref(), Some("Pet"));
+
+        // The handler extracts and documents the request variant
+        let files = AxumTemplate::from_openapi(&openapi).unwrap();
+        let (_, handlers) = files
+            .iter()
+            .find(|(path, _)| path == "src/pets/handlers.rs")
+            .unwrap();
+        assert!(handlers.contains("    request_body = PetCreate,\n"));
+        assert!(handlers.contains("body: Result<Json<PetCreate>, JsonRejection>"));
+
+        // Models without such fields are not split
+        let openapi = file_utils::openapi_from_file("src/test_data/petstore.json").unwrap();
+        let api = Api::from_openapi(&openapi);
+        assert!(api.models.iter().all(|m| !m.name.ends_with("Create")));
+    }
+
+    #[test]
+    fn test_read_write_only_variants_are_transitive() {
+        let openapi: OpenAPI = serde_json::from_str(
+            r##"{
+                "openapi": "3.0.3",
+                "info": {"title": "Owners", "version": "1"},
+                "paths": {},
+                "components": {"schemas": {
+                    "Pet": {"type": "object", "required": ["id"], "properties": {
+                        "id": {"type": "integer", "format": "int64", "readOnly": true}
+                    }},
+                    "Owner": {"type": "object", "required": ["pets"], "properties": {
+                        "pets": {"type": "array", "items": {"$ref": "#/components/schemas/Pet"}}
+                    }},
+                    "Household": {"type": "object", "properties": {
+                        "owner": {"$ref": "#/components/schemas/Owner"}
+                    }},
+                    "OwnerCreate": {"type": "object"}
+                }}
+            }"##,
+        )
+        .unwrap();
+        let api = Api::from_openapi(&openapi);
+
+        let names: Vec<&str> = api.models.iter().map(|m| m.name.as_str()).collect();
+        assert_eq!(
+            names,
+            vec![
+                "Pet",
+                "PetCreate",
+                "Owner",
+                "OwnerCreate2",
+                "Household",
+                "HouseholdCreate",
+                "OwnerCreate"
+            ]
+        );
+        // Responses hold the response forms, requests the request forms
+        assert_eq!(field_type(&api.models, "Owner", "pets"), "Vec<Pet>");
+        assert_eq!(field_type(&api.models, "OwnerCreate2", "pets"), "Vec<PetCreate>");
+        assert_eq!(
+            field_type(&api.models, "HouseholdCreate", "owner"),
+            "Option<OwnerCreate2>"
+        );
+    }
+
+    #[test]
+    fn test_rename_types() {
+        let renames = BTreeMap::from([("Pet".to_string(), "PetCreate".to_string())]);
+        assert_eq!(rename_types("Pet", &renames), "PetCreate");
+        assert_eq!(rename_types("Vec<Pet>", &renames), "Vec<PetCreate>");
+        assert_eq!(
+            rename_types("std::collections::HashMap<String, Pet>", &renames),
+            "std::collections::HashMap<String, PetCreate>"
+        );
+        assert_eq!(rename_types("Vec<PetList>", &renames), "Vec<PetList>");
+    }
+
+    #[test]
+    fn test_discriminated_models() {
+        let openapi = file_utils::openapi_from_file("src/test_data/polymorphic.json").unwrap();
+        let models = translate(&openapi, &TypeMapper::default());
+        let model = |name: &str| models.iter().find(|m| m.name == name).unwrap();
+        let variants = |name: &str| -> Vec<(String, String, String)> {
+            let discriminator = model(name).discriminator.as_ref().unwrap();
+            discriminator
+                .variants
+                .iter()
+                .map(|v| (v.name.clone(), v.tag.clone(), v.rust_type.clone()))
+                .collect()
+        };
+        let fields = |name: &str| -> Vec<String> {
+            model(name).fields.iter().map(|f| f.name.clone()).collect()
+        };
+
+        // The mapping names the variants, holding payloads without the tag
+        // serde consumes; a dog can have a pet mother
+        let pet = model("Pet");
+        assert_eq!(pet.discriminator.as_ref().unwrap().property, "petType");
+        assert!(pet.fields.is_empty());
+        assert_eq!(
+            variants("Pet"),
+            vec![
+                ("Cat".to_string(), "cat".to_string(), "PetCat".to_string()),
+                ("Dog".to_string(), "dog".to_string(), "Box<PetDog>".to_string()),
+            ]
+        );
+        assert_eq!(fields("PetCat"), vec!["name", "hunting_skill"]);
+        assert_eq!(fields("PetDog"), vec!["name", "pack_size", "mother", "puppies"]);
+        assert_eq!(field_type(&models, "PetDog", "name"), "String");
+        assert_eq!(field_type(&models, "PetDog", "mother"), "Option<Box<Pet>>");
+        assert_eq!(field_type(&models, "PetDog", "puppies"), "Option<Vec<Pet>>");
+        assert_eq!(model("PetDog").spec_name, "Dog");
+
+        // Subtypes inherit through allOf and, used on their own, keep the tag
+        assert_eq!(fields("Cat"), vec!["pet_type", "name", "hunting_skill"]);
+        assert_eq!(fields("Dog"), vec!["pet_type", "name", "pack_size", "mother", "puppies"]);
+        // Only the payload is inside the enum, so a dog holds its mother inline
+        assert_eq!(field_type(&models, "Dog", "mother"), "Option<Pet>");
+        // Subtypes missing from the mapping are plain structs
+        assert_eq!(fields("Lizard"), vec!["pet_type", "name", "loves_rocks"]);
+
+        // Without a mapping, oneOf members are tagged with their names
+        assert_eq!(
+            variants("Shape"),
+            vec![
+                ("Circle".to_string(), "Circle".to_string(), "ShapeCircle".to_string()),
+                ("Square".to_string(), "Square".to_string(), "ShapeSquare".to_string()),
+            ]
+        );
+        assert_eq!(fields("ShapeCircle"), vec!["radius"]);
+        assert_eq!(fields("Circle"), vec!["kind", "radius"]);
+    }
+}
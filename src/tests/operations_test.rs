@@ -1,11 +1,7 @@
 #[cfg(test)]
 mod tests {
-    use crate::functions_translator::{
-        FunctionSignature, 
-        ParameterSignature, 
-        ParameterLocation,
-        RequestBodySignature,
-        ResponseSignature
+    use crate::ir::operations::{
+        translate, Operation, Parameter, ParameterLocation, RequestBody, Response,
     };
     use crate::file_utils;
     use openapiv3::ReferenceOr;
@@ -22,7 +18,7 @@ mod tests {
     #[test]
     fn test_function_signature_creation() {
         init();
-        let sig = FunctionSignature::new();
+        let sig = Operation::new();
         assert_eq!(sig.fn_name, "");
         log::debug!("Function Signature: {:?}", sig.fn_name);
         assert_eq!(sig.http_method, "");
@@ -32,12 +28,14 @@ mod tests {
 
     #[test]
     fn test_parameter_handling() {
-        let mut sig = FunctionSignature::new();
-        sig.params.push(ParameterSignature {
+        let mut sig = Operation::new();
+        sig.params.push(Parameter {
             name: "id".to_string(),
+            rust_name: "id".to_string(),
             rust_type: "i32".to_string(),
             location: ParameterLocation::Path,
             description: Some("Item ID".to_string()),
+            required: true,
         });
 
         assert_eq!(sig.params.len(), 1);
@@ -48,8 +46,8 @@ mod tests {
 
     #[test]
     fn test_request_body_handling() {
-        let mut sig = FunctionSignature::new();
-        sig.request_body = Some(RequestBodySignature {
+        let mut sig = Operation::new();
+        sig.request_body = Some(RequestBody {
             rust_type: "CreateTodo".to_string(),
             description: Some("New todo item".to_string()),
         });
@@ -61,16 +59,18 @@ mod tests {
 
     #[test]
     fn test_response_handling() {
-        let mut sig = FunctionSignature::new();
-        sig.responses.push(ResponseSignature {
-            status: 200,
+        let mut sig = Operation::new();
+        sig.responses.push(Response {
+            status: Some(200),
+            range: None,
             description: Some("Success".to_string()),
             rust_type: Some("Vec<Todo>".to_string()),
+            content_type: Some("application/json".to_string()),
         });
 
         assert_eq!(sig.responses.len(), 1);
         let response = &sig.responses[0];
-        assert_eq!(response.status, 200);
+        assert_eq!(response.status, Some(200));
         assert_eq!(response.rust_type.as_ref().unwrap(), "Vec<Todo>");
     }
 
@@ -79,8 +79,7 @@ mod tests {
         init();
         
         let openapi = file_utils::create_minimal_openapi();
-        let sig = FunctionSignature::new();
-        let result = sig.translate(&openapi);
+        let result = translate(&openapi);
 
         log::debug!("Result Length: {:?}", result.len());
 
@@ -104,8 +103,7 @@ mod tests {
         assert!(!openapi.info.title.is_empty());
         assert!(!openapi.info.version.is_empty());
         
-        let sig = FunctionSignature::new();
-        let result = sig.translate(&openapi);
+        let result = translate(&openapi);
 
         log::debug!("Result Length: {:?}", result.len());
 
@@ -132,9 +130,6 @@ mod tests {
     fn test_v4_exclusion_in_folder() {
         init();
         
-        let mut sig1 = FunctionSignature::new();
-        sig1.folder = "v4_test".to_string();
-
         let min_openapi = file_utils::create_minimal_openapi();
         
         let path_item = min_openapi.paths.paths.first().unwrap().1;
@@ -146,13 +141,10 @@ mod tests {
             }
         }
 
-        let result1 = sig1.translate(&min_openapi);
+        let result1 = translate(&min_openapi);
         log::debug!("Result1: {:?}", result1[0].folder);
         assert_eq!(result1[0].folder, "tag_test");
 
-        let mut sig2 = FunctionSignature::new();
-        sig2.folder = "api_test".to_string();
-
         let mut min_openapi = file_utils::create_minimal_openapi();
         let path_item = min_openapi.paths.paths.first_mut().unwrap().1;
         if let ReferenceOr::Item(path_item) = path_item {
@@ -161,7 +153,7 @@ mod tests {
             }
         }
 
-        let result2 = sig2.translate(&min_openapi);
+        let result2 = translate(&min_openapi);
         log::debug!("Result2: {:?}", result2[0].folder);
         assert_eq!(result2[0].folder, "default");
     }
@@ -183,9 +175,47 @@ mod tests {
         ];
 
         for (input, expected) in cases {
-            let result = FunctionSignature::to_snake_case(input);
+            let result = Operation::to_snake_case(input);
             log::debug!("Result: {:?}", result);
             assert_eq!(result, expected, "Failed for input: {}", input);
         }
     }
+
+    #[test]
+    fn test_problem_json_responses() {
+        let openapi: openapiv3::OpenAPI = serde_json::from_value(serde_json::json!({
+            "openapi": "3.0.0",
+            "info": { "title": "Problems", "version": "1.0.0" },
+            "paths": {
+                "/items": {
+                    "get": {
+                        "operationId": "listItems",
+                        "responses": {
+                            "200": {
+                                "description": "Items",
+                                "content": { "application/json": { "schema": { "type": "string" } } }
+                            },
+                            "400": {
+                                "description": "Bad request",
+                                "content": {
+                                    "application/problem+json": { "schema": { "type": "object" } }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }))
+        .unwrap();
+
+        let result = translate(&openapi);
+        let responses = &result[0].responses;
+        assert_eq!(responses.len(), 2);
+        assert_eq!(responses[0].content_type.as_deref(), Some("application/json"));
+        assert_eq!(responses[1].status, Some(400));
+        assert_eq!(
+            responses[1].content_type.as_deref(),
+            Some("application/problem+json")
+        );
+    }
 }
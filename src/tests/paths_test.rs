@@ -0,0 +1,80 @@
+#[cfg(test)]
+mod tests {
+    use crate::file_utils;
+    use crate::ir::operations::axum_route;
+    use crate::ir::Api;
+    use openapiv3::OpenAPI;
+
+    #[test]
+    fn test_axum_route() {
+        assert_eq!(axum_route("/pets"), "/pets");
+        assert_eq!(axum_route("/pets/{petId}"), "/pets/:pet_id");
+        assert_eq!(axum_route("/files/{type}/{x-name}"), "/files/:type/:x_name");
+    }
+
+    #[test]
+    fn test_petstore_paths() {
+        let openapi = file_utils::openapi_from_file("src/test_data/petstore.json").unwrap();
+        let api = Api::from_openapi(&openapi);
+
+        let names: Vec<_> = api.paths.iter().map(|path| path.name.as_str()).collect();
+        assert_eq!(names, ["PetsPath", "PetsPetIdPath"]);
+
+        let pet = &api.paths[1];
+        assert_eq!(pet.path, "/pets/{petId}");
+        assert_eq!(pet.route, "/pets/:pet_id");
+        assert_eq!(pet.fields.len(), 1);
+        assert_eq!(pet.fields[0].name, "pet_id");
+        assert_eq!(pet.fields[0].rust_type, "String");
+        assert!(api.paths[0].fields.is_empty());
+    }
+
+    #[test]
+    fn test_path_field_types() {
+        let openapi: OpenAPI = serde_json::from_value(serde_json::json!({
+            "openapi": "3.0.0",
+            "info": { "title": "Orders", "version": "1.0.0" },
+            "paths": {
+                "/": {
+                    "get": { "responses": { "204": { "description": "Up" } } }
+                },
+                "/orders/{orderId}/items/{sku}": {
+                    "get": {
+                        "parameters": [
+                            {
+                                "name": "orderId", "in": "path", "required": true,
+                                "schema": { "type": "integer", "format": "int64" }
+                            },
+                            {
+                                "name": "sku", "in": "path", "required": true,
+                                "schema": { "type": "array", "items": { "type": "string" } }
+                            }
+                        ],
+                        "responses": { "204": { "description": "Found" } }
+                    },
+                    "delete": {
+                        "responses": { "204": { "description": "Deleted" } }
+                    }
+                }
+            }
+        }))
+        .unwrap();
+        let api = Api::from_openapi(&openapi);
+
+        // One struct per path, not per operation
+        assert_eq!(api.paths.len(), 2);
+        assert_eq!(api.paths[0].name, "RootPath");
+        assert_eq!(api.paths[0].route, "/");
+
+        let items = &api.paths[1];
+        assert_eq!(items.name, "OrdersOrderIdItemsSkuPath");
+        assert_eq!(items.route, "/orders/:order_id/items/:sku");
+        let fields: Vec<_> = items
+            .fields
+            .iter()
+            .map(|field| (field.name.as_str(), field.rust_type.as_str()))
+            .collect();
+        // Captures that are not scalars fall back to `String`
+        assert_eq!(fields, [("order_id", "i64"), ("sku", "String")]);
+    }
+}
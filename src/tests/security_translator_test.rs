@@ -0,0 +1,196 @@
+#[cfg(test)]
+mod tests {
+    use crate::file_utils;
+    use crate::ir::resolve::Resolver;
+    use crate::ir::{operations, Api};
+    use crate::security_translator::{operation_security, SchemeKind, SecurityTranslator};
+    use crate::AxumTemplate;
+    use std::path::PathBuf;
+
+    fn load_secured() -> openapiv3::OpenAPI {
+        file_utils::openapi_from_file(PathBuf::from("src/test_data/secured.json")).unwrap()
+    }
+
+    #[test]
+    fn test_translate_security_schemes() {
+        let openapi = load_secured();
+        let schemes = SecurityTranslator::new().translate(&openapi, &Resolver::new(&openapi));
+
+        assert_eq!(schemes.len(), 5);
+
+        let api_key = schemes.iter().find(|s| s.name == "api_key").unwrap();
+        assert_eq!(api_key.kind, SchemeKind::ApiKey);
+        assert_eq!(api_key.variant, "ApiKey");
+        assert_eq!(api_key.location.as_deref(), Some("header"));
+        assert_eq!(api_key.key_name.as_deref(), Some("X-API-Key"));
+
+        let cookie = schemes.iter().find(|s| s.name == "session_cookie").unwrap();
+        assert_eq!(cookie.location.as_deref(), Some("cookie"));
+
+        let basic = schemes.iter().find(|s| s.name == "basic_auth").unwrap();
+        assert_eq!(basic.kind, SchemeKind::HttpBasic);
+
+        let bearer = schemes.iter().find(|s| s.name == "bearer_auth").unwrap();
+        assert_eq!(bearer.kind, SchemeKind::HttpBearer);
+
+        let oauth = schemes.iter().find(|s| s.name == "petstore_auth").unwrap();
+        assert_eq!(oauth.kind, SchemeKind::OAuth2);
+        assert_eq!(oauth.scopes, vec!["write:pets", "read:pets"]);
+    }
+
+    #[test]
+    fn test_colliding_scheme_variants() {
+        let openapi: openapiv3::OpenAPI = serde_json::from_value(serde_json::json!({
+            "openapi": "3.0.0",
+            "info": { "title": "Keys", "version": "1.0.0" },
+            "security": [{ "apiKey": [] }],
+            "paths": {},
+            "components": {
+                "securitySchemes": {
+                    "api_key": { "type": "apiKey", "in": "header", "name": "X-Key" },
+                    "apiKey": { "type": "apiKey", "in": "query", "name": "key" }
+                }
+            }
+        }))
+        .unwrap();
+
+        // Both camel-case to "ApiKey"
+        let schemes = SecurityTranslator::new().translate(&openapi, &Resolver::new(&openapi));
+        let variants: Vec<_> = schemes.iter().map(|s| s.variant.as_str()).collect();
+        assert_eq!(variants, ["ApiKey", "ApiKey2"]);
+
+        // Requirements refer to the same variants
+        let resolver = Resolver::new(&openapi);
+        let (security, diagnostics) = operation_security(&openapi, &resolver, None, "/paths");
+        assert_eq!(security[0].schemes[0].variant, "ApiKey2");
+        assert!(diagnostics.is_empty());
+    }
+
+    #[test]
+    fn test_referenced_and_undeclared_schemes() {
+        let openapi: openapiv3::OpenAPI = serde_json::from_value(serde_json::json!({
+            "openapi": "3.0.0",
+            "info": { "title": "Tokens", "version": "1.0.0" },
+            "paths": {
+                "/pets": {
+                    "get": {
+                        "operationId": "listPets",
+                        "security": [{ "bearer": [] }, { "oauth": ["read"] }],
+                        "responses": { "200": { "description": "Pets" } }
+                    },
+                    "post": {
+                        "operationId": "addPet",
+                        "security": [{ "broken": [] }],
+                        "responses": { "201": { "description": "Added" } }
+                    }
+                }
+            },
+            "components": {
+                "securitySchemes": {
+                    "bearer": { "$ref": "#/components/securitySchemes/jwt" },
+                    "jwt": { "type": "http", "scheme": "bearer" },
+                    "broken": { "$ref": "#/components/securitySchemes/missing" }
+                }
+            }
+        }))
+        .unwrap();
+
+        // References are followed; the broken one is left out
+        let schemes = SecurityTranslator::new().translate(&openapi, &Resolver::new(&openapi));
+        let variants: Vec<_> = schemes.iter().map(|s| s.variant.as_str()).collect();
+        assert_eq!(variants, ["Bearer", "Jwt"]);
+        assert_eq!(schemes[0].kind, SchemeKind::HttpBearer);
+
+        // Requirements that can never be met are dropped, and an operation
+        // left with none is skipped
+        let api = Api::from_openapi(&openapi);
+        assert_eq!(api.operations.len(), 1);
+        let list_pets = &api.operations[0];
+        assert_eq!(list_pets.security.len(), 1);
+        assert_eq!(list_pets.security[0].schemes[0].variant, "Bearer");
+
+        let messages: Vec<_> = api.diagnostics.iter().map(|d| d.to_string()).collect();
+        assert_eq!(
+            messages,
+            [
+                "at #/paths/~1pets/get/security/1: security scheme `oauth` is not declared",
+                "at #/paths/~1pets/post/security/0: security scheme `broken`: reference \
+                 `#/components/securitySchemes/missing` does not resolve",
+                "at #/paths/~1pets/post: no security requirement can be met, so the operation \
+                 is skipped",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_operation_security_requirements() {
+        let openapi = load_secured();
+        let operations = operations::translate(&openapi);
+        let find = |name: &str| operations.iter().find(|op| op.fn_name == name).unwrap();
+
+        // Inherits the global requirement
+        let list_pets = find("list_pets");
+        assert_eq!(list_pets.security.len(), 1);
+        assert_eq!(list_pets.security[0].schemes[0].name, "api_key");
+
+        // Overrides it, with scopes
+        let create_pets = find("create_pets");
+        assert_eq!(create_pets.security.len(), 1);
+        assert_eq!(create_pets.security[0].schemes[0].variant, "PetstoreAuth");
+        assert_eq!(
+            create_pets.security[0].schemes[0].scopes,
+            vec!["write:pets", "read:pets"]
+        );
+
+        // Two alternatives, the second requiring both schemes
+        let show_pet = find("show_pet_by_id");
+        assert_eq!(show_pet.security.len(), 2);
+        assert_eq!(show_pet.security[0].schemes.len(), 1);
+        assert_eq!(show_pet.security[1].schemes.len(), 2);
+
+        // `security: []` opts out of the global requirement
+        assert!(find("health").security.is_empty());
+    }
+
+    #[test]
+    fn test_security_module_generation() {
+        let openapi = load_secured();
+        let files = AxumTemplate::from_openapi(&openapi).unwrap();
+
+        let (_, security) = files
+            .iter()
+            .find(|(path, _)| path == "src/security.rs")
+            .expect("security.rs not generated");
+        assert!(security.contains("pub trait Authenticator"));
+        assert!(security.contains("Self::ApiKey => header_value(parts, \"X-API-Key\")"));
+        assert!(security.contains("Self::SessionCookie => cookie_value(parts, \"session\")"));
+        assert!(security.contains("Self::BasicAuth => basic_credentials(parts)"));
+        assert!(security.contains(
+            "pub const CREATE_PETS: &[Requirement] = &[\n    &[(SecurityScheme::PetstoreAuth, &[\"write:pets\", \"read:pets\"])],\n];"
+        ));
+        assert!(security.contains("pub const HEALTH: &[Requirement] = &[];"));
+
+        let (_, mod_rs) = files.iter().find(|(path, _)| path == "src/mod.rs").unwrap();
+        assert!(mod_rs.contains("pub mod security;"));
+
+        // Only modules with secured operations take the authenticator
+        assert!(mod_rs.contains("crate::health::handlers::create_router(handlers.clone())"));
+        assert!(mod_rs.contains(
+            "crate::pets::handlers::create_router(handlers.clone(), authenticator.clone())"
+        ));
+        let (_, health) = files
+            .iter()
+            .find(|(path, _)| path == "src/health/handlers.rs")
+            .unwrap();
+        assert!(health.contains("pub fn create_router<H: Handlers>(handlers: H) -> Router {"));
+        assert!(!health.contains("crate::security"));
+    }
+
+    #[test]
+    fn test_no_security_module_without_schemes() {
+        let openapi =
+            file_utils::openapi_from_file(PathBuf::from("src/test_data/petstore.json")).unwrap();
+        let files = AxumTemplate::from_openapi(&openapi).unwrap();
+        assert!(!files.iter().any(|(path, _)| path == "src/security.rs"));
+    }
+}
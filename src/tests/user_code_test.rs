@@ -0,0 +1,88 @@
+#[cfg(test)]
+mod tests {
+    use crate::user_code::{anchors, extract_regions, fill_regions, ORPHANS_FILE};
+    use crate::writer::write_files;
+    use std::fs;
+
+    const GENERATED: &str = "\
+#[utoipa::path(get, path = \"/pets\")]
+// <user-code name=\"list_pets\">
+// </user-code>
+
+#[utoipa::path(post, path = \"/pets\")]
+// <user-code name=\"create_pets\">
+// </user-code>
+";
+
+    const EDITED: &str = "\
+#[utoipa::path(get, path = \"/pets\")]
+// <user-code name=\"list_pets\">
+pub async fn list_pets() -> &'static str {
+    \"[]\"
+}
+// </user-code>
+
+#[utoipa::path(post, path = \"/pets\")]
+// <user-code name=\"create_pets\">
+// </user-code>
+";
+
+    #[test]
+    fn test_extract_and_fill_regions() {
+        assert_eq!(anchors(GENERATED), vec!["list_pets", "create_pets"]);
+
+        // Empty regions are not worth preserving
+        let regions = extract_regions(EDITED);
+        assert_eq!(regions.len(), 1);
+        assert_eq!(
+            regions["list_pets"],
+            "pub async fn list_pets() -> &'static str {\n    \"[]\"\n}\n"
+        );
+
+        assert_eq!(fill_regions(GENERATED, &regions), EDITED);
+        // Filling is idempotent
+        assert_eq!(fill_regions(EDITED, &regions), EDITED);
+    }
+
+    #[test]
+    fn test_regions_follow_operation_across_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let first = vec![("src/pets/handlers.rs".to_string(), GENERATED.to_string())];
+        write_files(dir.path(), &first, false).unwrap();
+        fs::write(dir.path().join("src/pets/handlers.rs"), EDITED).unwrap();
+
+        // The operation moved to another module
+        let second = vec![("src/animals/handlers.rs".to_string(), GENERATED.to_string())];
+        let report = write_files(dir.path(), &second, true).unwrap();
+        assert!(report.orphaned.is_empty());
+        assert_eq!(
+            fs::read_to_string(dir.path().join("src/animals/handlers.rs")).unwrap(),
+            EDITED
+        );
+        assert!(!dir.path().join("src/pets/handlers.rs").exists());
+    }
+
+    #[test]
+    fn test_orphaned_region_is_kept_and_restored() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("src/pets/handlers.rs");
+        let first = vec![("src/pets/handlers.rs".to_string(), GENERATED.to_string())];
+        write_files(dir.path(), &first, false).unwrap();
+        fs::write(&path, EDITED).unwrap();
+
+        // `listPets` was removed from the spec
+        let without_anchor = GENERATED.replace("list_pets", "show_pet_by_id");
+        let second = vec![("src/pets/handlers.rs".to_string(), without_anchor.clone())];
+        let report = write_files(dir.path(), &second, false).unwrap();
+        assert_eq!(report.orphaned, vec!["list_pets"]);
+        assert_eq!(fs::read_to_string(&path).unwrap(), without_anchor);
+        let orphans = fs::read_to_string(dir.path().join(ORPHANS_FILE)).unwrap();
+        assert!(orphans.contains("pub async fn list_pets()"));
+
+        // ... and added back
+        let report = write_files(dir.path(), &first, false).unwrap();
+        assert!(report.orphaned.is_empty());
+        assert_eq!(fs::read_to_string(&path).unwrap(), EDITED);
+        assert!(!dir.path().join(ORPHANS_FILE).exists());
+    }
+}
@@ -0,0 +1,115 @@
+#[cfg(test)]
+mod tests {
+    use crate::config::{DocsConfig, ErrorStyle, GeneratorConfig, MapType, Target};
+    use crate::file_utils;
+    use crate::validate::{validate_and_format, Origin, OriginMarker};
+    use crate::AxumTemplate;
+    use std::fs;
+
+    fn markers() -> Vec<OriginMarker> {
+        vec![
+            OriginMarker {
+                origin: Origin::Operation {
+                    name: "list_pets".to_string(),
+                    method: "get".to_string(),
+                    path: "/pets".to_string(),
+                },
+                needles: vec!["// GET /pets".to_string()],
+            },
+            OriginMarker {
+                origin: Origin::Schema("Pet".to_string()),
+                needles: vec!["/// Pet model".to_string(), "struct Pet ".to_string()],
+            },
+        ]
+    }
+
+    #[test]
+    fn test_invalid_rust_is_attributed() {
+        let content = "\
+use serde::Serialize;
+
+// GET /pets
+#[utoipa::path(get, path = \"/pets\")]
+fn list_pets() {}
+
+/// Pet model
+#[derive(Serialize)]
+pub struct Pet {
+    pub pet-name: String,
+}
+";
+        let error = validate_and_format("src/models.rs", "models.rs.jinja", content, &markers())
+            .unwrap_err();
+        assert_eq!(error.origin, Some(Origin::Schema("Pet".to_string())));
+        assert_eq!(error.origin.as_ref().unwrap().pointer(), "/components/schemas/Pet");
+        assert_eq!(error.template, "models.rs.jinja");
+        assert_eq!((error.line, error.column), (10, 12));
+        assert_eq!(error.snippet, "    pub pet-name: String,");
+        assert_eq!(
+            error.to_string(),
+            format!(
+                "src/models.rs:10:12: {} (rendered by template `models.rs.jinja` for schema `Pet`)\n    pub pet-name: String,",
+                error.message
+            )
+        );
+
+        let error = validate_and_format(
+            "src/pets/handlers.rs",
+            "axum_utoipa.rs.jinja",
+            "// GET /pets\n#[utoipa::path(get, path = \"/pets\")]\nfn list_pets( {}\n",
+            &markers(),
+        )
+        .unwrap_err();
+        assert_eq!(
+            error.origin,
+            Some(Origin::Operation {
+                name: "list_pets".to_string(),
+                method: "get".to_string(),
+                path: "/pets".to_string(),
+            })
+        );
+        assert_eq!(error.origin.as_ref().unwrap().pointer(), "/paths/~1pets/get");
+        assert!(error.to_string().contains("for operation `list_pets` (GET /pets))"));
+    }
+
+    #[test]
+    fn test_valid_rust_is_formatted() {
+        let formatted =
+            validate_and_format("src/mod.rs", "mod.rs.jinja", "pub mod   pets ;", &[]).unwrap();
+        assert_eq!(formatted, "pub mod pets;\n");
+    }
+
+    /// Every spec fixture generates valid Rust, whatever the options.
+    #[test]
+    fn test_fixtures_generate_valid_rust() {
+        let configs = [
+            GeneratorConfig::default(),
+            GeneratorConfig {
+                error_style: ErrorStyle::Problem,
+                docs: Some(DocsConfig::default()),
+                map_type: MapType::BTreeMap,
+                ..Default::default()
+            },
+            GeneratorConfig {
+                target: Target::Client,
+                ..Default::default()
+            },
+        ];
+
+        let mut specs = 0;
+        for entry in fs::read_dir("src/test_data").unwrap() {
+            let path = entry.unwrap().path();
+            // Not every fixture is a spec (some are sample responses)
+            let Ok(openapi) = file_utils::openapi_from_file(&path) else {
+                continue;
+            };
+            specs += 1;
+            for config in &configs {
+                if let Err(e) = AxumTemplate::from_openapi_with_config(&openapi, config) {
+                    panic!("{} ({:?}): {}", path.display(), config, e);
+                }
+            }
+        }
+        assert!(specs >= 5, "only {} spec fixtures found", specs);
+    }
+}
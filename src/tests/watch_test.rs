@@ -0,0 +1,90 @@
+#[cfg(test)]
+mod tests {
+    use crate::config::GeneratorConfig;
+    use crate::watch::{regenerate, wait_for_change, WatchOptions};
+    use notify::event::{AccessKind, ModifyKind};
+    use notify::{Event, EventKind};
+    use std::collections::BTreeSet;
+    use std::fs;
+    use std::path::PathBuf;
+    use std::sync::mpsc;
+    use std::time::{Duration, Instant};
+
+    fn options(dir: &tempfile::TempDir) -> WatchOptions {
+        WatchOptions {
+            input: dir.path().join("api.json"),
+            output_dir: dir.path().join("out"),
+            config: GeneratorConfig::default(),
+            remove_stale: false,
+            debounce: Duration::from_millis(50),
+        }
+    }
+
+    #[test]
+    fn test_regenerate_survives_parse_errors() {
+        let dir = tempfile::tempdir().unwrap();
+        let options = options(&dir);
+        fs::copy("src/test_data/petstore.json", &options.input).unwrap();
+
+        let report = regenerate(&options).unwrap();
+        assert!(!report.created.is_empty());
+        let handlers = options.output_dir.join("src/pets/handlers.rs");
+        let generated = fs::read_to_string(&handlers).unwrap();
+
+        // A half-saved spec is reported and leaves the output untouched
+        fs::write(&options.input, "{ \"openapi\": ").unwrap();
+        assert!(regenerate(&options).is_err());
+        assert_eq!(fs::read_to_string(&handlers).unwrap(), generated);
+
+        fs::copy("src/test_data/petstore.json", &options.input).unwrap();
+        let report = regenerate(&options).unwrap();
+        assert!(report.created.is_empty() && report.updated.is_empty());
+    }
+
+    #[test]
+    fn test_wait_for_change_debounces() {
+        let spec = PathBuf::from("/specs/api.yaml");
+        let files: BTreeSet<PathBuf> = [spec.clone()].into_iter().collect();
+        let (tx, rx) = mpsc::channel();
+
+        // Reads and unrelated files are ignored; a burst of writes counts once
+        tx.send(Ok(Event::new(EventKind::Access(AccessKind::Any)).add_path(spec.clone())))
+            .unwrap();
+        tx.send(Ok(Event::new(EventKind::Modify(ModifyKind::Any))
+            .add_path(PathBuf::from("/specs/notes.txt"))))
+            .unwrap();
+        for _ in 0..3 {
+            tx.send(Ok(Event::new(EventKind::Modify(ModifyKind::Any)).add_path(spec.clone())))
+                .unwrap();
+        }
+
+        let started = Instant::now();
+        wait_for_change(&rx, &files, Duration::from_millis(50)).unwrap();
+        assert!(started.elapsed() >= Duration::from_millis(50));
+        assert!(rx.try_recv().is_err());
+
+        // A stream of unrelated events does not delay the change
+        tx.send(Ok(Event::new(EventKind::Modify(ModifyKind::Any)).add_path(spec.clone())))
+            .unwrap();
+        let noise = tx.clone();
+        let busy = std::thread::spawn(move || {
+            for _ in 0..50 {
+                let swap = Event::new(EventKind::Modify(ModifyKind::Any))
+                    .add_path(PathBuf::from("/specs/.api.yaml.swp"));
+                if noise.send(Ok(swap)).is_err() {
+                    break;
+                }
+                std::thread::sleep(Duration::from_millis(10));
+            }
+        });
+        let started = Instant::now();
+        wait_for_change(&rx, &files, Duration::from_millis(50)).unwrap();
+        assert!(started.elapsed() < Duration::from_millis(400));
+        busy.join().unwrap();
+        while rx.try_recv().is_ok() {}
+
+        // The watch ends when the watcher goes away
+        drop(tx);
+        assert!(wait_for_change(&rx, &files, Duration::from_millis(50)).is_err());
+    }
+}
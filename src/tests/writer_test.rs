@@ -0,0 +1,80 @@
+#[cfg(test)]
+mod tests {
+    use crate::writer::{read_manifest, write_files, MANIFEST_FILE};
+    use std::fs;
+    use std::path::PathBuf;
+
+    fn files(entries: &[(&str, &str)]) -> Vec<(String, String)> {
+        entries
+            .iter()
+            .map(|(path, content)| (path.to_string(), content.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_write_files_skips_identical_content() {
+        let dir = tempfile::tempdir().unwrap();
+        let first = files(&[("src/mod.rs", "pub mod pets;"), ("src/pets/handlers.rs", "// v1")]);
+
+        let report = write_files(dir.path(), &first, false).unwrap();
+        assert_eq!(report.created.len(), 2);
+        assert_eq!(report.to_string(), "2 created, 0 updated, 0 unchanged, 0 deleted");
+
+        let modified = fs::metadata(dir.path().join("src/mod.rs"))
+            .unwrap()
+            .modified()
+            .unwrap();
+
+        let second = files(&[("src/mod.rs", "pub mod pets;"), ("src/pets/handlers.rs", "// v2")]);
+        let report = write_files(dir.path(), &second, false).unwrap();
+        assert_eq!(report.unchanged, vec![PathBuf::from("src/mod.rs")]);
+        assert_eq!(report.updated, vec![PathBuf::from("src/pets/handlers.rs")]);
+        assert_eq!(
+            fs::read_to_string(dir.path().join("src/pets/handlers.rs")).unwrap(),
+            "// v2"
+        );
+
+        // The identical file was not rewritten
+        let unchanged_modified = fs::metadata(dir.path().join("src/mod.rs"))
+            .unwrap()
+            .modified()
+            .unwrap();
+        assert_eq!(modified, unchanged_modified);
+    }
+
+    #[test]
+    fn test_write_files_stale_modules() {
+        let dir = tempfile::tempdir().unwrap();
+        let first = files(&[
+            ("src/mod.rs", "pub mod pets;\npub mod stores;"),
+            ("src/pets/handlers.rs", "// pets"),
+            ("src/stores/handlers.rs", "// stores"),
+        ]);
+        write_files(dir.path(), &first, false).unwrap();
+        fs::write(dir.path().join("src/notes.rs"), "// hand-written").unwrap();
+
+        let second = files(&[("src/mod.rs", "pub mod pets;"), ("src/pets/handlers.rs", "// pets")]);
+
+        // Stale files are reported but kept unless asked, and stay tracked
+        let report = write_files(dir.path(), &second, false).unwrap();
+        assert_eq!(report.stale, vec![PathBuf::from("src/stores/handlers.rs")]);
+        assert!(report.deleted.is_empty());
+        assert!(dir.path().join("src/stores/handlers.rs").exists());
+        assert!(read_manifest(dir.path())
+            .unwrap()
+            .contains(&"src/stores/handlers.rs".to_string()));
+
+        let report = write_files(dir.path(), &second, true).unwrap();
+        assert_eq!(report.deleted, vec![PathBuf::from("src/stores/handlers.rs")]);
+        assert_eq!(report.to_string(), "0 created, 0 updated, 2 unchanged, 1 deleted");
+        assert!(!dir.path().join("src/stores").exists());
+
+        // Files the generator never wrote are left alone
+        assert!(dir.path().join("src/notes.rs").exists());
+        assert_eq!(
+            read_manifest(dir.path()).unwrap(),
+            vec!["src/mod.rs", "src/pets/handlers.rs"]
+        );
+        assert!(dir.path().join(MANIFEST_FILE).exists());
+    }
+}
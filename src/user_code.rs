@@ -0,0 +1,163 @@
+//! Hand-written regions preserved across regenerations
+//!
+//! Templates emit empty regions anchored by operation:
+//!
+//! ```text
+//! // <user-code name="list_pets">
+//! // </user-code>
+//! ```
+//!
+//! Whatever is written between the markers is carried into the region with
+//! the same name on the next run, in whichever file it is generated.
+
+use log::warn;
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// Holds regions whose anchor disappeared, relative to the output directory.
+/// They are restored if the anchor comes back.
+pub const ORPHANS_FILE: &str = ".openapi-axum-generator.orphaned.rs";
+
+pub(crate) const REGION_END: &str = "// </user-code>";
+
+/// Region bodies keyed by name.
+pub type Regions = BTreeMap<String, String>;
+
+/// The name of a `// <user-code name="...">` marker line.
+pub(crate) fn region_start(line: &str) -> Option<&str> {
+    line.trim()
+        .strip_prefix("// <user-code name=\"")?
+        .strip_suffix("\">")
+}
+
+/// Whether `line` is a `// </user-code>` marker.
+pub(crate) fn is_region_end(line: &str) -> bool {
+    line.trim() == REGION_END
+}
+
+/// Extracts the non-empty regions of `content`.
+pub fn extract_regions(content: &str) -> Regions {
+    let mut regions = Regions::new();
+    let mut current: Option<(&str, String)> = None;
+    for line in content.split_inclusive('\n') {
+        match current.as_mut() {
+            None => {
+                if let Some(name) = region_start(line) {
+                    current = Some((name, String::new()));
+                }
+            }
+            Some((name, body)) => {
+                if is_region_end(line) {
+                    if !body.trim().is_empty() {
+                        regions.insert(name.to_string(), std::mem::take(body));
+                    }
+                    current = None;
+                } else {
+                    body.push_str(line);
+                }
+            }
+        }
+    }
+    regions
+}
+
+/// The names of the regions declared in `content`.
+pub fn anchors(content: &str) -> Vec<String> {
+    content
+        .lines()
+        .filter_map(region_start)
+        .map(str::to_string)
+        .collect()
+}
+
+/// Replaces the body of every region of `content` found in `regions`.
+pub fn fill_regions(content: &str, regions: &Regions) -> String {
+    let mut filled = String::with_capacity(content.len());
+    let mut skipping = false;
+    for line in content.split_inclusive('\n') {
+        if skipping {
+            if !is_region_end(line) {
+                continue;
+            }
+            skipping = false;
+        }
+        filled.push_str(line);
+        if let Some(body) = region_start(line).and_then(|name| regions.get(name)) {
+            filled.push_str(body);
+            skipping = true;
+        }
+    }
+    filled
+}
+
+/// The generated files with their regions filled from disk, and the regions
+/// whose anchor is no longer generated.
+pub struct Preserved {
+    pub files: Vec<(String, String)>,
+    pub orphaned: Regions,
+}
+
+/// Carries the regions found in `sources` (and in the orphans file) under
+/// `output_dir` into `files`.
+pub fn preserve(
+    output_dir: &Path,
+    files: &[(String, String)],
+    sources: &[String],
+) -> io::Result<Preserved> {
+    let mut regions = Regions::new();
+    for relative in sources
+        .iter()
+        .map(String::as_str)
+        .chain(files.iter().map(|(path, _)| path.as_str()))
+        .chain([ORPHANS_FILE])
+    {
+        match fs::read_to_string(output_dir.join(relative)) {
+            Ok(content) => {
+                for (name, body) in extract_regions(&content) {
+                    // Regions in live files win over orphaned copies
+                    if relative != ORPHANS_FILE || !regions.contains_key(&name) {
+                        regions.insert(name, body);
+                    }
+                }
+            }
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+            Err(e) => return Err(e),
+        }
+    }
+
+    let mut orphaned = regions.clone();
+    for (_, content) in files {
+        for name in anchors(content) {
+            orphaned.remove(&name);
+        }
+    }
+    for name in orphaned.keys() {
+        warn!(
+            "User code region `{}` has no anchor in the spec anymore; kept in {}",
+            name, ORPHANS_FILE
+        );
+    }
+
+    let files = files
+        .iter()
+        .map(|(path, content)| (path.clone(), fill_regions(content, &regions)))
+        .collect();
+    Ok(Preserved { files, orphaned })
+}
+
+/// Renders orphaned regions with their markers, so they can be restored or copied back.
+pub fn render_orphans(orphaned: &Regions) -> String {
+    let mut content = String::from(
+        "// User code whose anchor disappeared from the spec; restored when it comes back.\n",
+    );
+    for (name, body) in orphaned {
+        content.push_str(&format!("\n// <user-code name=\"{}\">\n{}{}\n", name, body, REGION_END));
+    }
+    content
+}
+
+#[cfg(test)]
+#[path = "tests/user_code_test.rs"]
+mod user_code_tests;
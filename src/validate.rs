@@ -0,0 +1,131 @@
+//! Checks that generated Rust parses before it is written
+
+use std::error::Error;
+use std::fmt;
+
+use crate::file_utils::json_pointer;
+use crate::formatter::format_rust;
+
+/// What in the spec produced a stretch of generated code.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Origin {
+    /// An operation, by handler name, lowercase method and path template.
+    Operation {
+        name: String,
+        method: String,
+        path: String,
+    },
+    /// A component schema, by its name in the spec.
+    Schema(String),
+}
+
+impl Origin {
+    /// JSON pointer to the spec item.
+    pub fn pointer(&self) -> String {
+        match self {
+            Self::Operation { method, path, .. } => json_pointer(["paths", path, method]),
+            Self::Schema(name) => json_pointer(["components", "schemas", name]),
+        }
+    }
+}
+
+impl fmt::Display for Origin {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Operation { name, method, path } => {
+                write!(f, "operation `{}` ({} {})", name, method.to_uppercase(), path)
+            }
+            Self::Schema(name) => write!(f, "schema `{}`", name),
+        }
+    }
+}
+
+/// Text that, when found on a generated line, attributes the lines from
+/// there on to `origin`.
+#[derive(Debug, Clone)]
+pub struct OriginMarker {
+    pub origin: Origin,
+    pub needles: Vec<String>,
+}
+
+/// A generated file that is not valid Rust.
+#[derive(Debug, Clone)]
+pub struct InvalidRustError {
+    /// The generated file, relative to the output directory.
+    pub path: String,
+
+    /// The template that rendered the file.
+    pub template: String,
+
+    /// The spec item the offending code was generated for, when known.
+    pub origin: Option<Origin>,
+
+    /// 1-based line and column of the error in the rendered file.
+    pub line: usize,
+    pub column: usize,
+
+    /// The offending line as rendered.
+    pub snippet: String,
+
+    /// The parser's message.
+    pub message: String,
+}
+
+impl fmt::Display for InvalidRustError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}:{}:{}: {} (rendered by template `{}`",
+            self.path, self.line, self.column, self.message, self.template
+        )?;
+        if let Some(origin) = &self.origin {
+            write!(f, " for {}", origin)?;
+        }
+        write!(f, ")\n    {}", self.snippet.trim())
+    }
+}
+
+impl Error for InvalidRustError {}
+
+/// Parses `content` with `syn` and pretty-prints it, attributing a syntax
+/// error to the nearest marker above the offending line.
+pub fn validate_and_format(
+    path: &str,
+    template: &str,
+    content: &str,
+    markers: &[OriginMarker],
+) -> Result<String, Box<InvalidRustError>> {
+    let error = match format_rust(content) {
+        Ok(formatted) => return Ok(formatted),
+        Err(error) => error,
+    };
+    let start = error.span().start();
+    let lines: Vec<&str> = content.lines().collect();
+    let line_index = start.line.saturating_sub(1);
+
+    let origin = lines
+        .get(..=line_index.min(lines.len().saturating_sub(1)))
+        .unwrap_or_default()
+        .iter()
+        .rev()
+        .find_map(|line| {
+            markers
+                .iter()
+                .find(|marker| marker.needles.iter().any(|needle| line.contains(needle.as_str())))
+        })
+        .map(|marker| marker.origin.clone());
+
+    Err(Box::new(InvalidRustError {
+        path: path.to_string(),
+        template: template.to_string(),
+        origin,
+        line: start.line,
+        column: start.column + 1,
+        snippet: lines.get(line_index).copied().unwrap_or_default().to_string(),
+        message: error.to_string(),
+    }))
+}
+
+#[cfg(test)]
+#[path = "tests/validate_test.rs"]
+mod validate_tests;
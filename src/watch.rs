@@ -0,0 +1,144 @@
+//! Regenerates whenever the spec or a file it references changes
+
+use log::{debug, info};
+use notify::{Event, EventKind, RecursiveMode, Watcher};
+use std::collections::BTreeSet;
+use std::error::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::time::{Duration, Instant};
+
+use crate::config::GeneratorConfig;
+use crate::file_utils::external_refs;
+use crate::generator::Generator;
+use crate::writer::WriteReport;
+
+/// Options for [`watch`].
+#[derive(Debug, Clone)]
+pub struct WatchOptions {
+    /// The spec to generate from.
+    pub input: PathBuf,
+
+    /// Where the generated files are written.
+    pub output_dir: PathBuf,
+
+    /// Options for each generator run.
+    pub config: GeneratorConfig,
+
+    /// Whether files that are no longer generated are removed.
+    pub remove_stale: bool,
+
+    /// How long changes must settle before regenerating.
+    pub debounce: Duration,
+}
+
+/// Generates once, then again after every change to the spec or the files
+/// it references. Errors are printed and the watch goes on.
+pub fn watch(options: &WatchOptions) -> Result<(), Box<dyn Error>> {
+    let (tx, rx) = mpsc::channel();
+    let mut watcher = notify::recommended_watcher(tx)?;
+    let mut watched_dirs = BTreeSet::new();
+
+    loop {
+        match regenerate(options) {
+            Ok(report) => println!("Regenerated {} ({})", options.output_dir.display(), report),
+            Err(e) => eprintln!("error: {}: {}", options.input.display(), e),
+        }
+
+        // References may have been added or removed by the last edit
+        let files = watched_files(&options.input);
+        // Editors often replace files on save, so watch their directories
+        for dir in files.iter().filter_map(|file| file.parent()) {
+            if watched_dirs.contains(dir) {
+                continue;
+            }
+            debug!("Watching {}", dir.display());
+            // A missing directory is retried after the next change
+            match watcher.watch(dir, RecursiveMode::NonRecursive) {
+                Ok(()) => {
+                    watched_dirs.insert(dir.to_path_buf());
+                }
+                Err(e) => eprintln!("warning: cannot watch {}: {}", dir.display(), e),
+            }
+        }
+
+        info!("Waiting for changes to {} files", files.len());
+        wait_for_change(&rx, &files, options.debounce)?;
+    }
+}
+
+/// Runs the generator and writes its output incrementally.
+pub fn regenerate(options: &WatchOptions) -> Result<WriteReport, Box<dyn Error>> {
+    let project = Generator::builder()
+        .spec(options.input.as_path())
+        .config(options.config.clone())
+        .build()?
+        .generate()?;
+    Ok(project.write(&options.output_dir, options.remove_stale)?)
+}
+
+/// The spec and the files it references, as absolute paths.
+fn watched_files(input: &Path) -> BTreeSet<PathBuf> {
+    let refs = external_refs(input).unwrap_or_else(|e| {
+        debug!("Cannot follow references of {}: {}", input.display(), e);
+        Vec::new()
+    });
+    std::iter::once(input.to_path_buf())
+        .chain(refs)
+        .map(|path| absolute(&path))
+        .collect()
+}
+
+/// Resolves the directory of `path`, which may not exist while an editor
+/// replaces it.
+fn absolute(path: &Path) -> PathBuf {
+    let dir = match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent,
+        _ => Path::new("."),
+    };
+    let dir = fs::canonicalize(dir).unwrap_or_else(|_| dir.to_path_buf());
+    match path.file_name() {
+        Some(name) => dir.join(name),
+        None => dir,
+    }
+}
+
+/// Blocks until one of `files` changes and no further change to them
+/// arrives for `debounce`. Other events in the watched directories are
+/// ignored, so a busy directory cannot hold regeneration back.
+pub fn wait_for_change(
+    rx: &Receiver<notify::Result<Event>>,
+    files: &BTreeSet<PathBuf>,
+    debounce: Duration,
+) -> Result<(), Box<dyn Error>> {
+    let is_relevant = |event: &notify::Result<Event>| match event {
+        Ok(event) => {
+            !matches!(event.kind, EventKind::Access(_))
+                && event.paths.iter().any(|path| files.contains(path))
+        }
+        Err(_) => false,
+    };
+
+    loop {
+        if is_relevant(&rx.recv()?) {
+            break;
+        }
+    }
+    let mut deadline = Instant::now() + debounce;
+    loop {
+        match rx.recv_timeout(deadline.saturating_duration_since(Instant::now())) {
+            Ok(event) => {
+                if is_relevant(&event) {
+                    deadline = Instant::now() + debounce;
+                }
+            }
+            Err(RecvTimeoutError::Timeout) => return Ok(()),
+            Err(e) => return Err(e.into()),
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/watch_test.rs"]
+mod watch_tests;
@@ -0,0 +1,165 @@
+//! Writes generated files to disk, touching only what changed
+
+use log::debug;
+use std::collections::BTreeSet;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::user_code::{self, ORPHANS_FILE};
+
+/// The manifest, relative to the output directory, listing the files written
+/// by the previous run.
+pub const MANIFEST_FILE: &str = ".openapi-axum-generator.manifest";
+
+/// What a write did, as paths relative to the output directory.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct WriteReport {
+    pub created: Vec<PathBuf>,
+    pub updated: Vec<PathBuf>,
+    pub unchanged: Vec<PathBuf>,
+    /// Files from the previous run that are no longer generated and were removed.
+    pub deleted: Vec<PathBuf>,
+    /// Files from the previous run that are no longer generated but were kept.
+    pub stale: Vec<PathBuf>,
+    /// User code regions whose anchor is no longer generated.
+    pub orphaned: Vec<String>,
+}
+
+impl fmt::Display for WriteReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} created, {} updated, {} unchanged, {} deleted",
+            self.created.len(),
+            self.updated.len(),
+            self.unchanged.len(),
+            self.deleted.len()
+        )?;
+        if !self.stale.is_empty() {
+            write!(f, ", {} stale", self.stale.len())?;
+        }
+        if !self.orphaned.is_empty() {
+            write!(f, ", {} orphaned user code regions", self.orphaned.len())?;
+        }
+        Ok(())
+    }
+}
+
+/// Writes `files` under `output_dir`, skipping files whose content is already
+/// on disk, and records them in the manifest.
+///
+/// User code regions on disk are carried into the new content. Files listed
+/// in the previous manifest but no longer generated are removed when
+/// `remove_stale` is set, and reported as stale otherwise.
+pub fn write_files(
+    output_dir: &Path,
+    files: &[(String, String)],
+    remove_stale: bool,
+) -> io::Result<WriteReport> {
+    let mut report = WriteReport::default();
+    let previous = read_manifest(output_dir)?;
+    let preserved = user_code::preserve(output_dir, files, &previous)?;
+
+    for (relative, content) in &preserved.files {
+        let path = output_dir.join(relative);
+        match fs::read(&path) {
+            Ok(existing) if existing == content.as_bytes() => {
+                debug!("Unchanged: {}", path.display());
+                report.unchanged.push(PathBuf::from(relative));
+                continue;
+            }
+            Ok(_) => report.updated.push(PathBuf::from(relative)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {
+                report.created.push(PathBuf::from(relative))
+            }
+            Err(e) => return Err(e),
+        }
+        debug!("Writing: {}", path.display());
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&path, content)?;
+    }
+
+    let generated: BTreeSet<&str> = files.iter().map(|(path, _)| path.as_str()).collect();
+    for relative in previous.iter().filter(|p| !generated.contains(p.as_str())) {
+        let path = output_dir.join(relative);
+        if !path.exists() {
+            continue;
+        }
+        if remove_stale {
+            debug!("Removing stale file: {}", path.display());
+            fs::remove_file(&path)?;
+            remove_empty_parents(output_dir, &path)?;
+            report.deleted.push(PathBuf::from(relative));
+        } else {
+            report.stale.push(PathBuf::from(relative));
+        }
+    }
+
+    // Files that were kept stay tracked so a later run can still remove them
+    let mut tracked: BTreeSet<String> = generated.iter().map(|p| p.to_string()).collect();
+    tracked.extend(report.stale.iter().map(|p| p.to_string_lossy().into_owned()));
+    write_manifest(output_dir, &tracked)?;
+
+    let orphans = output_dir.join(ORPHANS_FILE);
+    if preserved.orphaned.is_empty() {
+        if orphans.exists() {
+            fs::remove_file(orphans)?;
+        }
+    } else {
+        fs::write(orphans, user_code::render_orphans(&preserved.orphaned))?;
+        report.orphaned = preserved.orphaned.into_keys().collect();
+    }
+
+    Ok(report)
+}
+
+/// Reads the relative paths recorded by the previous run, if any.
+pub fn read_manifest(output_dir: &Path) -> io::Result<Vec<String>> {
+    match fs::read_to_string(output_dir.join(MANIFEST_FILE)) {
+        Ok(content) => Ok(content
+            .lines()
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .map(str::to_string)
+            .collect()),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Vec::new()),
+        Err(e) => Err(e),
+    }
+}
+
+fn write_manifest(output_dir: &Path, paths: &BTreeSet<String>) -> io::Result<()> {
+    let mut content = String::from("# Files generated by openapi-axum-generator\n");
+    for path in paths {
+        content.push_str(path);
+        content.push('\n');
+    }
+    let manifest = output_dir.join(MANIFEST_FILE);
+    if fs::read_to_string(&manifest).ok().as_deref() != Some(content.as_str()) {
+        fs::create_dir_all(output_dir)?;
+        fs::write(manifest, content)?;
+    }
+    Ok(())
+}
+
+/// Removes the directories left empty by deleting `path`, up to `output_dir`.
+fn remove_empty_parents(output_dir: &Path, path: &Path) -> io::Result<()> {
+    let mut dir = path.parent();
+    while let Some(current) = dir {
+        if current == output_dir || !current.starts_with(output_dir) {
+            break;
+        }
+        if fs::read_dir(current)?.next().is_some() {
+            break;
+        }
+        fs::remove_dir(current)?;
+        dir = current.parent();
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+#[path = "tests/writer_test.rs"]
+mod writer_tests;